@@ -1,14 +1,45 @@
+use advent_of_code_2024::config::{self, Config, Settings};
+use advent_of_code_2024::days::Parts;
+use advent_of_code_2024::runner::Solver;
+use advent_of_code_2024::{plugin, runner, years};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 pub fn bench_days(c: &mut Criterion) {
-    for day in advent_of_code_2024::days::days() {
-        let day_name = format!("day{:02}", day);
+    // Read `aoc.toml` and the `AOC_*` variables like the binary does.
+    config::init(Config::load(Settings::from_env()).unwrap());
+
+    for (year, day) in years::all_days() {
+        let day_name = format!("{}/day{:02}", year, day);
         c.bench_function(&day_name, |b| {
-            let input_path = format!("inputs/day{:02}.txt", day);
-            let input = std::fs::read_to_string(input_path).unwrap();
-            let day_solve = advent_of_code_2024::days::solver(day).unwrap();
+            let input = std::fs::read_to_string(runner::input_path(year, day)).unwrap();
+            let day_solve = years::solver(year, day).unwrap();
+
+            b.iter(|| black_box(day_solve(&input, &[], Parts::Both)));
+        });
+
+        for variant in years::variants(year, day) {
+            c.bench_function(&format!("{}/{}", day_name, variant.name), |b| {
+                let input = std::fs::read_to_string(runner::input_path(year, day)).unwrap();
+
+                b.iter(|| black_box((variant.solver)(&input, &[], Parts::Both)));
+            });
+        }
+    }
+
+    for solver in plugin::solvers() {
+        // Skip plugin variants shadowed by a built-in or an earlier plugin, which would be
+        // benchmarked under the same name.
+        let found = Solver::find(solver.year, solver.day, Some(&solver.variant));
+        if !matches!(found, Some(Solver::Plugin(found)) if std::ptr::eq(found, solver)) {
+            continue;
+        }
+
+        let name = format!("{}/day{:02}/{}", solver.year, solver.day, solver.variant);
+        c.bench_function(&name, |b| {
+            let input =
+                std::fs::read_to_string(runner::input_path(solver.year, solver.day)).unwrap();
 
-            b.iter(|| black_box(day_solve(&input)));
+            b.iter(|| black_box(solver.solve(&input, Parts::Both)));
         });
     }
 }
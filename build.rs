@@ -0,0 +1,107 @@
+//! Discovers the years in `src/years/` and the day modules in each of them, and generates the
+//! `impl_years!` invocation for them, so new days and years don't have to be registered by hand.
+//!
+//! Also generates the C header for the C ABI in `src/ffi.rs` into `OUT_DIR`; a test checks that
+//! the committed copy in `include/aoc.h` is up to date.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/years");
+
+    let mut years = vec![];
+    for entry in fs::read_dir("src/years").unwrap() {
+        let path = entry.unwrap().path();
+        let Some(module) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some(year) = module
+            .strip_prefix('y')
+            .and_then(|year| year.parse::<usize>().ok())
+        else {
+            // Not a year directory (e.g. the `dayN.rs` template).
+            continue;
+        };
+        if path.is_dir() {
+            println!("cargo:rerun-if-changed={}", path.display());
+            years.push((year, module.to_string(), day_modules(&path)));
+        }
+    }
+    years.sort();
+
+    // Modules declared from a file included out of `OUT_DIR` are resolved relative to that
+    // directory, so point each one at its source file explicitly.
+    let years_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/years");
+    let mut generated = "impl_years! {\n".to_string();
+    for (year, year_module, modules) in &years {
+        generated += &format!("    {} => {} {{\n", year_module, year);
+        for (day, module) in modules {
+            let path = years_dir.join(year_module).join(format!("{}.rs", module));
+            generated += &format!(
+                "        {} = {} => {:?},\n",
+                module,
+                day,
+                path.to_str().unwrap()
+            );
+        }
+        generated += "    },\n";
+    }
+    generated += "}\n";
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("years.rs");
+    fs::write(out_path, generated).unwrap();
+
+    generate_header();
+}
+
+/// Writes `aoc.h` to `OUT_DIR`. A failure only warns, leaving the header empty, so that it fails the
+/// header test rather than the whole build.
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("aoc.h");
+    let header = cbindgen::Builder::new()
+        .with_src("src/ffi.rs")
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("AOC_H")
+        .with_autogen_warning("/* Generated by build.rs from src/ffi.rs, do not edit. */")
+        .with_documentation(true)
+        // The plugin ABI is not used by any exported function, so name it explicitly.
+        .include_item("AocPluginSolver")
+        .include_item("AocPluginRegisterFn")
+        .generate();
+    match header {
+        Ok(header) => {
+            header.write_to_file(out_path);
+        }
+        Err(err) => {
+            println!("cargo:warning=could not generate aoc.h: {}", err);
+            fs::write(out_path, "").unwrap();
+        }
+    }
+}
+
+/// Finds the `dayNN.rs` modules in a year directory, sorted by day.
+fn day_modules(dir: &Path) -> Vec<(usize, String)> {
+    let mut modules = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(module) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let Some(day) = module
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<usize>().ok())
+        else {
+            continue;
+        };
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            println!("cargo:rerun-if-changed={}", path.display());
+            modules.push((day, module.to_string()));
+        }
+    }
+    modules.sort();
+    modules
+}
@@ -0,0 +1,33 @@
+//! Serves a directory as a stand-in for the Advent of Code server, to try the network commands
+//! offline: `cargo run --example mock-server -- --session TOKEN`.
+
+// Shared with the tests, which also inspect the requests the server received.
+#[allow(dead_code)]
+#[path = "../tests/common/mock.rs"]
+mod mock;
+
+use clap::Parser;
+use mock::MockServer;
+use std::path::PathBuf;
+
+/// Serve puzzle inputs, pages and submission replies from a local directory, as a stand-in for
+/// the Advent of Code server.
+#[derive(Parser)]
+struct Cli {
+    /// Directory to serve, laid out as `<year>/dayNN/{input.txt,puzzle.html,answer-P.txt}`.
+    #[arg(long, default_value = "mock")]
+    dir: PathBuf,
+    /// Session cookie that clients must send.
+    #[arg(long, env = "AOC_SESSION")]
+    session: String,
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+}
+
+fn main() -> std::io::Result<()> {
+    let cli = Cli::parse();
+    let server = MockServer::start(&cli.dir, &cli.session, cli.port)?;
+    println!("Serving {} on {}", cli.dir.display(), server.url());
+    server.wait();
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! An example solver plugin: a variant of day 1 of 2024 that counts the right list with a hash map.
+//!
+//! Build it with `cargo build --example plugin` and copy the library from `target/debug/examples/`
+//! to `plugins/`. Then run it with `solve 1 --variant counting`.
+
+use advent_of_code_2024::ffi::{AocPluginSolver, AocSolution, AOC_ERROR, AOC_OK};
+use std::collections::HashMap;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::time::Instant;
+
+static SOLVERS: [AocPluginSolver; 1] = [AocPluginSolver {
+    year: 2024,
+    day: 1,
+    variant: c"counting".as_ptr(),
+    solve: solve_day01,
+    free,
+}];
+
+/// # Safety
+///
+/// `len` must point to a writable `usize`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_plugin_register_v1(len: *mut usize) -> *const AocPluginSolver {
+    len.write(SOLVERS.len());
+    SOLVERS.as_ptr()
+}
+
+fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), String> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let numbers = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<u64>, _>>();
+            match numbers.as_deref() {
+                Ok(&[left, right]) => Ok((left, right)),
+                _ => Err(format!("line {}: expected two numbers", i + 1)),
+            }
+        })
+        .collect()
+}
+
+unsafe extern "C" fn solve_day01(input: *const u8, input_len: usize, out: *mut AocSolution) -> i32 {
+    let out = &mut *out;
+    *out = AocSolution::empty();
+    let input = String::from_utf8_lossy(slice::from_raw_parts(input, input_len));
+
+    // Panics must not unwind into the host, so they are reported as errors instead.
+    panic::catch_unwind(AssertUnwindSafe(|| solve(&input, out))).unwrap_or_else(|_| {
+        free(out);
+        *out = AocSolution::empty();
+        out.error = to_c_string("solver panicked");
+        AOC_ERROR
+    })
+}
+
+fn solve(input: &str, out: &mut AocSolution) -> i32 {
+    let start = Instant::now();
+    let (mut left, mut right) = match parse(input) {
+        Ok(lists) => lists,
+        Err(err) => {
+            out.error = to_c_string(err);
+            return AOC_ERROR;
+        }
+    };
+    out.parse_ns = start.elapsed().as_nanos() as u64;
+
+    let start = Instant::now();
+    left.sort_unstable();
+    right.sort_unstable();
+    let part_1 = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum::<u64>();
+    out.part_1_ns = start.elapsed().as_nanos() as u64;
+
+    let start = Instant::now();
+    let mut counts = HashMap::new();
+    for r in right {
+        *counts.entry(r).or_insert(0) += 1;
+    }
+    let part_2 = left
+        .iter()
+        .map(|l| l * counts.get(l).unwrap_or(&0))
+        .sum::<u64>();
+    out.part_2_ns = start.elapsed().as_nanos() as u64;
+
+    out.part_1 = to_c_string(part_1);
+    out.part_2 = to_c_string(part_2);
+    out.elapsed_ns = out.parse_ns + out.part_1_ns + out.part_2_ns;
+    AOC_OK
+}
+
+fn to_c_string(s: impl ToString) -> *mut c_char {
+    CString::new(s.to_string()).unwrap().into_raw()
+}
+
+unsafe extern "C" fn free(solution: *mut AocSolution) {
+    let solution = &mut *solution;
+    for s in [
+        &mut solution.part_1,
+        &mut solution.part_2,
+        &mut solution.error,
+    ] {
+        if !s.is_null() {
+            drop(CString::from_raw(*s));
+            *s = ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,131 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by build.rs from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The day was solved.
+ */
+#define AOC_OK 0
+
+/**
+ * The day is not implemented.
+ */
+#define AOC_NOT_IMPLEMENTED 1
+
+/**
+ * The solver rejected the input; the reason is in `error`.
+ */
+#define AOC_ERROR 2
+
+/**
+ * The solver panicked; the panic message is in `error`.
+ */
+#define AOC_PANICKED 3
+
+/**
+ * A pointer was null or the input was not UTF-8.
+ */
+#define AOC_INVALID_ARGUMENT 4
+
+/**
+ * Version of the plugin ABI. It is part of the name of the registration symbol, so that plugins
+ * built against another version are never called.
+ */
+#define AOC_PLUGIN_ABI_VERSION 1
+
+/**
+ * The result of `aoc_solve`. The strings are NUL-terminated and owned by the library; release
+ * them with `aoc_solution_free`.
+ */
+typedef struct AocSolution {
+  /**
+   * The answer to part 1, or null if it is not implemented.
+   */
+  char *part_1;
+  /**
+   * The answer to part 2, or null if it is not implemented.
+   */
+  char *part_2;
+  /**
+   * Why the day could not be solved, or null if it was.
+   */
+  char *error;
+  uint64_t parse_ns;
+  uint64_t part_1_ns;
+  uint64_t part_2_ns;
+  uint64_t elapsed_ns;
+} AocSolution;
+
+/**
+ * Solves a puzzle input like `aoc_solve`, for a day that is known in advance.
+ */
+typedef int32_t (*AocSolveFn)(const uint8_t *input, uintptr_t input_len, struct AocSolution *out);
+
+/**
+ * Releases the strings of a solution filled in by an `AocSolveFn`.
+ */
+typedef void (*AocFreeFn)(struct AocSolution *solution);
+
+/**
+ * A solver exported by a plugin.
+ */
+typedef struct AocPluginSolver {
+  uint32_t year;
+  uint32_t day;
+  /**
+   * Name of the variant, NUL-terminated, to tell it apart from the built-in solver.
+   */
+  const char *variant;
+  AocSolveFn solve;
+  /**
+   * Releases the strings allocated by `solve`, which may use a different allocator.
+   */
+  AocFreeFn free;
+} AocPluginSolver;
+
+/**
+ * Signature of the `aoc_plugin_register_v1` symbol every plugin exports. Returns the plugin's
+ * solvers and writes their number to `len`. The solvers must stay valid while the plugin is
+ * loaded.
+ */
+typedef const struct AocPluginSolver *(*AocPluginRegisterFn)(uintptr_t *len);
+
+/**
+ * Solves `day` of `year` on the `input_len` bytes at `input`, writing the result to `out`.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes and `out` to a writable `AocSolution`.
+ */
+int32_t aoc_solve(uint32_t year,
+                  uint32_t day,
+                  const uint8_t *input,
+                  uintptr_t input_len,
+                  struct AocSolution *out);
+
+/**
+ * Releases the strings of a solution filled in by `aoc_solve`, and resets them to null.
+ *
+ * # Safety
+ *
+ * `solution` must be null or point to a solution filled in by `aoc_solve`.
+ */
+void aoc_solution_free(struct AocSolution *solution);
+
+/**
+ * Writes up to `capacity` of the implemented days of `year` to `out`, and returns how many
+ * there are in total.
+ *
+ * # Safety
+ *
+ * `out` must point to `capacity` writable values, or be null if `capacity` is 0.
+ */
+uintptr_t aoc_days(uint32_t year, uint32_t *out, uintptr_t capacity);
+
+#endif  /* AOC_H */
@@ -0,0 +1,347 @@
+use crate::config;
+use crate::days::{PartSolution, Parts};
+use crate::runner::{self, DayOutcome};
+use std::cell::OnceCell;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Path of the recorded answers for the given day.
+pub fn answers_path(year: usize, day: usize) -> String {
+    let path = config::get()
+        .answers_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day));
+    path.display().to_string()
+}
+
+/// Hashes a puzzle input with 64-bit FNV-1a, which (unlike `DefaultHasher`) is stable across Rust
+/// versions, so recorded hashes stay valid.
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The known-good answers for one day, along with the hash of the input they belong to.
+///
+/// Stored as a small line-based text file:
+///
+/// ```text
+/// input: 9a1c2e0f5b7d3a64
+/// part 1: 1234
+/// part 2: 5678
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answers {
+    pub input_hash: u64,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    pub fn new(input: &str) -> Self {
+        Self {
+            input_hash: hash_input(input),
+            part_1: None,
+            part_2: None,
+        }
+    }
+
+    pub fn load(year: usize, day: usize) -> io::Result<Option<Self>> {
+        match fs::read_to_string(answers_path(year, day)) {
+            Ok(contents) => contents
+                .parse()
+                .map(Some)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, year: usize, day: usize) -> io::Result<()> {
+        let path = answers_path(year, day);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_part(&mut self, part: u8, answer: &PartSolution) {
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.part_1 = answer,
+            2 => self.part_2 = answer,
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut input_hash = None;
+        let mut part_1 = None;
+        let mut part_2 = None;
+
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("invalid line: '{}'", line))?;
+            let value = value.trim();
+            match key.trim() {
+                "input" => {
+                    input_hash = Some(
+                        u64::from_str_radix(value, 16)
+                            .map_err(|_| format!("invalid input hash: '{}'", value))?,
+                    )
+                }
+                "part 1" => part_1 = Some(value.to_string()),
+                "part 2" => part_2 = Some(value.to_string()),
+                key => return Err(format!("unknown key: '{}'", key)),
+            }
+        }
+
+        Ok(Self {
+            input_hash: input_hash.ok_or("missing input hash")?,
+            part_1,
+            part_2,
+        })
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "input: {:016x}", self.input_hash)?;
+        if let Some(part_1) = &self.part_1 {
+            writeln!(f, "part 1: {}", part_1)?;
+        }
+        if let Some(part_2) = &self.part_2 {
+            writeln!(f, "part 2: {}", part_2)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub enum Verification {
+    /// All recorded answers match.
+    Pass,
+    /// No answers have been recorded for this day; not counted as a failure.
+    NoAnswers,
+    /// There is no puzzle input to compare a variant on; not counted as a failure.
+    NoInput,
+    /// The input has changed since the answers were recorded.
+    StaleInput,
+    /// The solver produced a different answer than the recorded one.
+    Mismatch(Vec<PartMismatch>),
+    /// The solver could not be run or did not produce a recorded part.
+    Fail(String),
+}
+
+#[derive(Clone)]
+pub struct PartMismatch {
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        !matches!(
+            self,
+            Verification::Pass | Verification::NoAnswers | Verification::NoInput
+        )
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Pass => write!(f, "pass"),
+            Verification::NoAnswers => write!(f, "no answers"),
+            Verification::NoInput => write!(f, "no input"),
+            Verification::StaleInput => write!(f, "stale-input"),
+            Verification::Mismatch(mismatches) => {
+                write!(f, "mismatch")?;
+                for mismatch in mismatches {
+                    write!(
+                        f,
+                        " (part {}: expected {}, got {})",
+                        mismatch.part, mismatch.expected, mismatch.actual
+                    )?;
+                }
+                Ok(())
+            }
+            Verification::Fail(reason) => write!(f, "fail: {}", reason),
+        }
+    }
+}
+
+/// The answers to both parts.
+type PartAnswers = (Option<String>, Option<String>);
+
+/// The puzzle input of a day and the answers its solution gives for it, each read and solved at
+/// most once, to verify the recorded answers and every variant against.
+pub struct Baseline {
+    pub year: usize,
+    pub day: usize,
+    input: OnceCell<Option<String>>,
+    answers: OnceCell<Result<PartAnswers, Verification>>,
+}
+
+impl Baseline {
+    pub fn new(year: usize, day: usize) -> Self {
+        Self {
+            year,
+            day,
+            input: OnceCell::new(),
+            answers: OnceCell::new(),
+        }
+    }
+
+    fn input(&self) -> Option<&str> {
+        self.input
+            .get_or_init(|| fs::read_to_string(runner::input_path(self.year, self.day)).ok())
+            .as_deref()
+    }
+
+    fn answers(&self) -> Result<&PartAnswers, Verification> {
+        let Some(input) = self.input() else {
+            return Err(Verification::Fail("no input".to_string()));
+        };
+        self.answers
+            .get_or_init(|| solve(self.year, self.day, None, input))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+}
+
+/// Runs the solver of a day on its puzzle input and compares the results with the recorded
+/// answers.
+pub fn verify_day(baseline: &Baseline) -> Verification {
+    let (year, day) = (baseline.year, baseline.day);
+    let answers = match Answers::load(year, day) {
+        Ok(Some(answers)) => answers,
+        Ok(None) => return Verification::NoAnswers,
+        Err(err) => return Verification::Fail(format!("{}: {}", answers_path(year, day), err)),
+    };
+
+    let Some(input) = baseline.input() else {
+        return Verification::Fail("no input".to_string());
+    };
+    if hash_input(input) != answers.input_hash {
+        return Verification::StaleInput;
+    }
+
+    let (part_1, part_2) = match baseline.answers() {
+        Ok(parts) => parts,
+        Err(verification) => return verification,
+    };
+
+    let mut mismatches = vec![];
+    for (part, actual) in [(1, part_1), (2, part_2)] {
+        let Some(expected) = answers.part(part) else {
+            continue;
+        };
+        let Some(actual) = actual else {
+            return Verification::Fail(format!("part {} not implemented", part));
+        };
+        if actual != expected {
+            mismatches.push(PartMismatch {
+                part,
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            });
+        }
+    }
+
+    if mismatches.is_empty() {
+        Verification::Pass
+    } else {
+        Verification::Mismatch(mismatches)
+    }
+}
+
+/// Runs `variant` of the solver of a day on its puzzle input and compares the results with those
+/// of the solution itself. Parts the variant does not implement are not compared.
+pub fn verify_variant(baseline: &Baseline, variant: &str) -> Verification {
+    let Some(input) = baseline.input() else {
+        return Verification::NoInput;
+    };
+
+    let expected = match baseline.answers() {
+        Ok(parts) => parts,
+        Err(Verification::Fail(reason)) => {
+            return Verification::Fail(format!("the solution failed: {}", reason))
+        }
+        Err(verification) => return verification,
+    };
+    let actual = match solve(baseline.year, baseline.day, Some(variant), input) {
+        Ok(parts) => parts,
+        Err(verification) => return verification,
+    };
+
+    let mismatches = [(1, &expected.0, actual.0), (2, &expected.1, actual.1)]
+        .into_iter()
+        .filter_map(|(part, expected, actual)| match (expected, actual) {
+            (Some(expected), Some(actual)) if *expected != actual => Some(PartMismatch {
+                part,
+                expected: expected.clone(),
+                actual,
+            }),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if mismatches.is_empty() {
+        Verification::Pass
+    } else {
+        Verification::Mismatch(mismatches)
+    }
+}
+
+/// Solves both parts of a day, or returns why that failed as a [`Verification`].
+fn solve(
+    year: usize,
+    day: usize,
+    variant: Option<&str>,
+    input: &str,
+) -> Result<(Option<String>, Option<String>), Verification> {
+    match runner::solve_input(year, day, variant, &[], input, Parts::Both) {
+        DayOutcome::Solved(solution) => Ok((
+            solution.part_1.map(|part| part.to_string()),
+            solution.part_2.map(|part| part.to_string()),
+        )),
+        DayOutcome::NotImplemented => Err(Verification::Fail("not implemented".to_string())),
+        DayOutcome::NoInput => Err(Verification::Fail("no input".to_string())),
+        outcome => Err(Verification::Fail(outcome.failure().unwrap_or_default())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_input, Answers};
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let answers = Answers {
+            input_hash: hash_input("125 17\n"),
+            part_1: Some("55312".to_string()),
+            part_2: None,
+        };
+        assert_eq!(answers.to_string().parse(), Ok(answers));
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert!("part 1: 1\n".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::{Error, Result};
+use crate::puzzle;
+use crate::session::Session;
+use reqwest::blocking::RequestBuilder;
+use reqwest::StatusCode;
+use std::fmt::{Display, Formatter};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as asked by the Advent of Code automation guidelines.
+pub const USER_AGENT: &str = concat!(
+    "github.com/Mesoptier/advent-of-code-2024 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+);
+
+/// Why a request to the Advent of Code server failed.
+#[derive(Debug)]
+pub enum FetchError {
+    /// 400: usually a missing or invalid session.
+    BadRequest,
+    /// 404 before the puzzle unlocks.
+    NotUnlocked,
+    /// 404 for anything else, e.g. a day that does not exist.
+    NotFound,
+    /// 5xx: the server had a problem, which for Advent of Code often means a malformed session.
+    Server(StatusCode),
+    /// Any other unexpected status.
+    Status(StatusCode),
+    /// The request could not be sent or the response could not be read.
+    Http(reqwest::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::BadRequest => f.write_str("bad request (400): is the session valid?"),
+            FetchError::NotUnlocked => f.write_str("puzzle not unlocked yet"),
+            FetchError::NotFound => f.write_str("not found (404)"),
+            FetchError::Server(status) => write!(f, "server error ({})", status),
+            FetchError::Status(status) => write!(f, "unexpected status {}", status),
+            FetchError::Http(err) => err.fmt(f),
+        }
+    }
+}
+
+impl FetchError {
+    /// Whether the request might succeed if it is tried again later.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Server(_) => true,
+            FetchError::Status(status) => *status == StatusCode::TOO_MANY_REQUESTS,
+            FetchError::Http(err) => err.is_timeout() || err.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> Self {
+        FetchError::Http(err)
+    }
+}
+
+impl From<FetchError> for Error {
+    fn from(err: FetchError) -> Self {
+        Error::new(err.to_string())
+    }
+}
+
+/// Classifies a response by its status and body.
+fn check_status(status: StatusCode, body: &str) -> Result<(), FetchError> {
+    match status {
+        status if status.is_success() => Ok(()),
+        StatusCode::BAD_REQUEST => Err(FetchError::BadRequest),
+        // The server answers requests for puzzles that are not out yet with this message.
+        StatusCode::NOT_FOUND if body.contains("before it unlocks") => Err(FetchError::NotUnlocked),
+        StatusCode::NOT_FOUND => Err(FetchError::NotFound),
+        status if status.is_server_error() => Err(FetchError::Server(status)),
+        status => Err(FetchError::Status(status)),
+    }
+}
+
+/// Talks to the Advent of Code server, or a stand-in for it at `base_url`.
+pub struct Client {
+    base_url: String,
+    session: Option<Session>,
+    http: reqwest::blocking::Client,
+}
+
+impl Client {
+    /// Creates a client for `base_url`. Without a session only public pages can be fetched.
+    pub fn new(base_url: &str, session: Option<Session>) -> Result<Self> {
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            http: reqwest::blocking::Client::builder()
+                .user_agent(USER_AGENT)
+                .build()?,
+        })
+    }
+
+    fn get(&self, path: &str) -> Result<String, FetchError> {
+        self.send(self.http.get(format!("{}{}", self.base_url, path)))
+    }
+
+    fn send(&self, mut request: RequestBuilder) -> Result<String, FetchError> {
+        if let Some(session) = &self.session {
+            request = request.header("cookie", format!("session={}", session.token()));
+        }
+        let response = request.send()?;
+        let status = response.status();
+        let body = response.text()?;
+        check_status(status, &body)?;
+        Ok(body)
+    }
+
+    /// Returns the name of the account the session belongs to, or `None` if the server does not
+    /// recognize the session.
+    pub fn whoami(&self, year: usize) -> Result<Option<String>, FetchError> {
+        let html = self.get(&format!("/{}", year))?;
+        // Logged in pages show e.g. `<div class="user">Name <span class="star-count">`.
+        let Some(start) = html.find("<div class=\"user\">") else {
+            return Ok(None);
+        };
+        let name = &html[start + "<div class=\"user\">".len()..];
+        let name = &name[..name.find('<').unwrap_or(name.len())];
+        Ok(Some(puzzle::decode_entities(name).trim().to_string()))
+    }
+
+    /// Fetches the puzzle input of the given day.
+    pub fn input(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Fetches the HTML of the puzzle page of the given day.
+    pub fn puzzle(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Fetches the JSON of a private leaderboard.
+    pub fn leaderboard(&self, year: usize, id: u64) -> Result<String, FetchError> {
+        self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+    }
+
+    /// Submits an answer, returning the HTML of the reply.
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        self.send(
+            self.http
+                .post(url)
+                .form(&[("level", level.as_str()), ("answer", answer)]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_status, FetchError};
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_check_status() {
+        assert!(check_status(StatusCode::OK, "1 2\n").is_ok());
+        assert!(matches!(
+            check_status(StatusCode::BAD_REQUEST, "Please log in"),
+            Err(FetchError::BadRequest)
+        ));
+        assert!(matches!(
+            check_status(
+                StatusCode::NOT_FOUND,
+                "Please don't repeatedly request this endpoint before it unlocks!"
+            ),
+            Err(FetchError::NotUnlocked)
+        ));
+        assert!(matches!(
+            check_status(StatusCode::NOT_FOUND, "404 Not Found"),
+            Err(FetchError::NotFound)
+        ));
+        assert!(matches!(
+            check_status(StatusCode::INTERNAL_SERVER_ERROR, ""),
+            Err(FetchError::Server(_))
+        ));
+    }
+}
@@ -0,0 +1,199 @@
+//! Project settings, read from `aoc.toml` and overridden per field by environment variables and
+//! command line flags.
+//!
+//! ```toml
+//! year = 2024
+//! inputs-dir = "inputs"
+//! puzzles-dir = "puzzles"
+//! answers-dir = "answers"
+//! plugins-dir = "plugins"
+//! base-url = "https://adventofcode.com"
+//! session-file = "~/.adventofcode.session"
+//! format = "text"
+//! stack-size = 64
+//! timeout = 60
+//! ```
+
+use crate::client::DEFAULT_BASE_URL;
+use crate::error::{Error, Result};
+use crate::report::Format;
+use clap::{Args, Parser};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Every setting is optional, so that the sources can be layered on top of each other.
+#[derive(Debug, Clone, Default, Args, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Settings {
+    /// Year of the event.
+    #[arg(long, global = true, env = "AOC_YEAR")]
+    pub year: Option<usize>,
+    /// Directory with the puzzle inputs.
+    #[arg(long, global = true, env = "AOC_INPUTS_DIR", value_name = "DIR")]
+    pub inputs_dir: Option<PathBuf>,
+    /// Directory with the puzzle descriptions.
+    #[arg(long, global = true, env = "AOC_PUZZLES_DIR", value_name = "DIR")]
+    pub puzzles_dir: Option<PathBuf>,
+    /// Directory with the recorded answers and guesses.
+    #[arg(long, global = true, env = "AOC_ANSWERS_DIR", value_name = "DIR")]
+    pub answers_dir: Option<PathBuf>,
+    /// Directory with the solver plugins.
+    #[arg(long, global = true, env = "AOC_PLUGINS_DIR", value_name = "DIR")]
+    pub plugins_dir: Option<PathBuf>,
+    /// Base URL of the Advent of Code server.
+    #[arg(long, global = true, env = "AOC_BASE_URL", value_name = "URL")]
+    pub base_url: Option<String>,
+    /// File that holds the session cookie.
+    #[arg(long, global = true, env = "AOC_SESSION_FILE", value_name = "PATH")]
+    pub session_file: Option<PathBuf>,
+    /// Output format: text, json or csv.
+    #[arg(long, global = true, env = "AOC_FORMAT")]
+    pub format: Option<Format>,
+    /// Stack size of the threads that run the solvers, in MiB.
+    #[arg(long, global = true, env = "AOC_STACK_SIZE", value_name = "MIB")]
+    pub stack_size: Option<usize>,
+    /// Give up on a day after this many seconds.
+    #[arg(long, global = true, env = "AOC_TIMEOUT", value_name = "SECS")]
+    pub timeout: Option<u64>,
+}
+
+impl Settings {
+    /// Reads the settings from the environment only, for callers without a command line.
+    pub fn from_env() -> Self {
+        #[derive(Parser)]
+        struct Env {
+            #[command(flatten)]
+            settings: Settings,
+        }
+        Env::parse_from(["aoc"]).settings
+    }
+
+    /// Reads the settings from a TOML file, or returns no settings if it does not exist.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| Error::new(format!("{}: {}", path.display(), err.message()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::new(format!("{}: {}", path.display(), err))),
+        }
+    }
+
+    /// Fills in the settings that are not set here from `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            year: self.year.or(other.year),
+            inputs_dir: self.inputs_dir.or(other.inputs_dir),
+            puzzles_dir: self.puzzles_dir.or(other.puzzles_dir),
+            answers_dir: self.answers_dir.or(other.answers_dir),
+            plugins_dir: self.plugins_dir.or(other.plugins_dir),
+            base_url: self.base_url.or(other.base_url),
+            session_file: self.session_file.or(other.session_file),
+            format: self.format.or(other.format),
+            stack_size: self.stack_size.or(other.stack_size),
+            timeout: self.timeout.or(other.timeout),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+    pub year: usize,
+    pub inputs_dir: PathBuf,
+    pub puzzles_dir: PathBuf,
+    pub answers_dir: PathBuf,
+    pub plugins_dir: PathBuf,
+    pub base_url: String,
+    /// The session file, or `None` for `~/.adventofcode.session`.
+    pub session_file: Option<PathBuf>,
+    pub format: Format,
+    /// Stack size of the threads that run the solvers, in bytes.
+    pub stack_size: usize,
+    /// How long a day may run, or `None` for no limit.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::from(Settings::default())
+    }
+}
+
+impl From<Settings> for Config {
+    fn from(settings: Settings) -> Self {
+        Self {
+            year: settings.year.unwrap_or(2024),
+            inputs_dir: settings.inputs_dir.unwrap_or_else(|| "inputs".into()),
+            puzzles_dir: settings.puzzles_dir.unwrap_or_else(|| "puzzles".into()),
+            answers_dir: settings.answers_dir.unwrap_or_else(|| "answers".into()),
+            plugins_dir: settings.plugins_dir.unwrap_or_else(|| "plugins".into()),
+            base_url: settings
+                .base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session_file: settings.session_file,
+            format: settings.format.unwrap_or_default(),
+            stack_size: settings.stack_size.unwrap_or(64) << 20,
+            timeout: settings.timeout.map(Duration::from_secs),
+        }
+    }
+}
+
+impl Config {
+    /// Combines `overrides` (from the command line and environment) with `aoc.toml` in the current
+    /// directory.
+    pub fn load(overrides: Settings) -> Result<Self> {
+        let file = Settings::from_file(Path::new(CONFIG_FILE))?;
+        Ok(Self::from(overrides.or(file)))
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Sets the configuration for the rest of the process. Must be called before anything reads it.
+pub fn init(config: Config) {
+    if CONFIG.set(config).is_err() {
+        panic!("configuration already initialized");
+    }
+}
+
+/// The configuration set by [`init`], or the defaults if it was never called.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Settings};
+    use crate::report::Format;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_settings() {
+        let file: Settings = toml::from_str(indoc::indoc! {r#"
+            year = 2023
+            inputs-dir = "data"
+            format = "json"
+        "#})
+        .unwrap();
+        let overrides = Settings {
+            year: Some(2022),
+            ..Settings::default()
+        };
+
+        let config = Config::from(overrides.or(file));
+        assert_eq!(config.year, 2022);
+        assert_eq!(config.inputs_dir, PathBuf::from("data"));
+        assert_eq!(config.puzzles_dir, PathBuf::from("puzzles"));
+        assert_eq!(config.answers_dir, PathBuf::from("answers"));
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.stack_size, 64 << 20);
+        assert_eq!(config.timeout, None);
+
+        assert!(toml::from_str::<Settings>("yaer = 2023").is_err());
+    }
+}
@@ -1,50 +1,155 @@
-macro_rules! impl_days {
-    ($($m:ident,)*) => {
-        $(pub mod $m;)*
+use crate::error::Result;
+use crate::params::{Param, ParamValue, Params};
+use std::time::{Duration, Instant};
 
-        pub fn days() -> Vec<usize> {
-            vec![$($m::DAY,)*]
-        }
+/// A day's puzzle, split into parsing the input and solving each part on the parsed input.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Day`",
+    note = "every day module must export `pub struct Solution` implementing `crate::days::Day`"
+)]
+pub trait Day {
+    type Input<'a>;
+    type Output1: Into<PartSolution>;
+    type Output2: Into<PartSolution>;
 
-        pub fn solver(day: usize) -> Option<fn(&str) -> DaySolution> {
-            match day {
-                $($m::DAY => Some(|input: &str|{
-                    let (part_1, part_2) = $m::solve(&input);
-                    (part_1.map(|p| p.into()), part_2.map(|p| p.into()))
-                }),)*
-                _ => None,
-            }
+    /// The puzzle parameters of this day, with the values of the puzzle input as defaults.
+    const PARAMS: &'static [Param] = &[];
+
+    /// Named alternative solutions of this day, besides this one.
+    const VARIANTS: &'static [Variant] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    /// Parses the input with the given parameter values. Days with parameters implement this, and
+    /// `parse` by calling it with [`Params::defaults`].
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Result<Self::Input<'a>> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Option<Self::Output1>;
+    fn part2(input: &Self::Input<'_>) -> Option<Self::Output2>;
+
+    /// Parses the input and solves both parts.
+    fn solve(input: &str) -> Result<Solutions<Self>> {
+        let input = Self::parse(input)?;
+        Ok((Self::part1(&input), Self::part2(&input)))
+    }
+}
+
+/// The solutions of both parts of a day, as returned by [`Day::solve`].
+pub type Solutions<D> = (Option<<D as Day>::Output1>, Option<<D as Day>::Output2>);
+
+/// Which parts of a day to solve.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Part1 => part == 1,
+            Parts::Part2 => part == 2,
         }
-    };
+    }
+}
+
+/// The solutions of a day along with how long each phase took. Parts that were not run have no
+/// solution and no time.
+pub struct TimedSolution {
+    pub part_1: Option<PartSolution>,
+    pub part_2: Option<PartSolution>,
+    pub parse_time: Duration,
+    pub part_1_time: Option<Duration>,
+    pub part_2_time: Option<Duration>,
 }
 
-impl_days! {
-    day01,
-    day02,
-    day03,
-    day04,
-    day05,
-    day06,
-    day07,
-    day08,
-    day09,
-    day10,
-    day11,
-    day12,
-    day13,
-    day14,
-    day15,
-    day16,
+impl TimedSolution {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self.part_1_time.unwrap_or_default()
+            + self.part_2_time.unwrap_or_default()
+    }
 }
 
-type DaySolution = (Option<PartSolution>, Option<PartSolution>);
+pub type DaySolver = fn(&str, &[ParamValue], Parts) -> Result<TimedSolution>;
+
+/// A named alternative solution of a day, e.g. a naive one to check the fast one against. Days
+/// register them in [`Day::VARIANTS`]:
+///
+/// ```ignore
+/// const VARIANTS: &'static [Variant] = &[Variant::new::<Naive>("naive")];
+/// ```
+#[derive(Copy, Clone)]
+pub struct Variant {
+    pub name: &'static str,
+    pub solver: DaySolver,
+}
+
+impl Variant {
+    pub const fn new<D: Day>(name: &'static str) -> Self {
+        Self {
+            name,
+            solver: run::<D>,
+        }
+    }
+}
+
+pub(crate) fn run<D: Day>(
+    input: &str,
+    params: &[ParamValue],
+    parts: Parts,
+) -> Result<TimedSolution> {
+    let params = Params::new(D::PARAMS, params)?;
+    let start = Instant::now();
+    let input = D::parse_with(input, &params)?;
+    let parse_time = start.elapsed();
+
+    let mut part_1 = None;
+    let mut part_1_time = None;
+    if parts.includes(1) {
+        let start = Instant::now();
+        part_1 = D::part1(&input).map(Into::into);
+        part_1_time = Some(start.elapsed());
+    }
+
+    let mut part_2 = None;
+    let mut part_2_time = None;
+    if parts.includes(2) {
+        let start = Instant::now();
+        part_2 = D::part2(&input).map(Into::into);
+        part_2_time = Some(start.elapsed());
+    }
+
+    Ok(TimedSolution {
+        part_1,
+        part_2,
+        parse_time,
+        part_1_time,
+        part_2_time,
+    })
+}
 
 macro_rules! part_solution {
     ($($i:ident => $t:ty,)*) => {
+        #[derive(Debug, Clone, PartialEq)]
         pub enum PartSolution {
             $($i($t),)*
         }
 
+        impl PartSolution {
+            /// Name of the variant, e.g. `"Usize"`.
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(PartSolution::$i(_) => stringify!($i),)*
+                }
+            }
+        }
+
         impl std::fmt::Display for PartSolution {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
                 match self {
@@ -1,162 +0,0 @@
-use crate::util::grid::VecGrid;
-use itertools::Itertools;
-use nom::bytes::complete::tag;
-use nom::character::complete::digit1;
-use nom::combinator::{map_res, opt, recognize};
-use nom::sequence::{preceded, separated_pair, tuple};
-use nom::IResult;
-use std::cmp::Ordering;
-
-pub const DAY: usize = 14;
-
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    solve_with_dimensions(input, [101, 103], [31, 33])
-}
-
-fn solve_with_dimensions(
-    input: &str,
-    dimensions: Vec2,
-    tree_dimensions: Vec2,
-) -> (Option<usize>, Option<usize>) {
-    let mut particles = input
-        .lines()
-        .map(|line| parse_particle(line).unwrap().1)
-        .collect_vec();
-
-    let get_quadrant = |particle: Particle| -> Option<usize> {
-        match (
-            particle.pos[0].cmp(&(dimensions[0] / 2)),
-            particle.pos[1].cmp(&(dimensions[1] / 2)),
-        ) {
-            (Ordering::Less, Ordering::Less) => Some(0),
-            (Ordering::Greater, Ordering::Less) => Some(1),
-            (Ordering::Less, Ordering::Greater) => Some(2),
-            (Ordering::Greater, Ordering::Greater) => Some(3),
-            _ => None,
-        }
-    };
-
-    // Part 1
-    let mut counts1 = [0; 4];
-    for mut particle in particles.iter().copied() {
-        for d in 0..2 {
-            particle.pos[d] = (particle.pos[d] + particle.vel[d] * 100).rem_euclid(dimensions[d]);
-        }
-
-        if let Some(quadrant) = get_quadrant(particle) {
-            counts1[quadrant] += 1;
-        }
-    }
-
-    // Part 2
-    let mut part_2 = None;
-
-    for time in 1..10000 {
-        let mut grid = VecGrid::from_data(
-            dimensions[0] as usize,
-            vec![false; (dimensions[0] * dimensions[1]) as usize],
-        );
-
-        for particle in &mut particles {
-            for d in 0..2 {
-                particle.pos[d] = (particle.pos[d] + particle.vel[d]).rem_euclid(dimensions[d]);
-            }
-
-            grid[(particle.pos[0] as usize, particle.pos[1] as usize)] = true;
-        }
-
-        let mut has_tree_rect = false;
-        'outer: for particle in &particles {
-            let top_left = particle.pos;
-
-            // Check that tree rect would be in bounds
-            for d in 0..2 {
-                if top_left[d] + tree_dimensions[d] >= dimensions[d] {
-                    continue 'outer;
-                }
-            }
-
-            for d in 0..2 {
-                let other_d = (d + 1) % 2;
-
-                for i in 0..tree_dimensions[d] {
-                    // Point along top/left edges
-                    let mut p = top_left;
-                    p[d] = top_left[d] + i;
-                    if !grid[(p[0] as usize, p[1] as usize)] {
-                        continue 'outer;
-                    }
-
-                    // Point along bottom/right edges
-                    p[other_d] = top_left[other_d] + tree_dimensions[other_d] - 1;
-                    if !grid[(p[0] as usize, p[1] as usize)] {
-                        continue 'outer;
-                    }
-                }
-            }
-
-            has_tree_rect = true;
-            break;
-        }
-
-        if has_tree_rect {
-            part_2 = Some(time as usize);
-        }
-    }
-
-    (Some(counts1.iter().product()), part_2)
-}
-
-type Vec2 = [isize; 2];
-
-#[derive(Debug, Copy, Clone)]
-struct Particle {
-    pos: Vec2,
-    vel: Vec2,
-}
-
-fn parse_particle(input: &str) -> IResult<&str, Particle> {
-    let (input, pos) = preceded(tag("p="), parse_vec2)(input)?;
-    let (input, vel) = preceded(tag(" v="), parse_vec2)(input)?;
-
-    Ok((input, Particle { pos, vel }))
-}
-
-fn parse_vec2(input: &str) -> IResult<&str, Vec2> {
-    let (input, (x, y)) = separated_pair(parse_isize, tag(","), parse_isize)(input)?;
-    Ok((input, [x, y]))
-}
-
-fn parse_isize(input: &str) -> IResult<&str, isize> {
-    map_res(recognize(tuple((opt(tag("-")), digit1))), |s: &str| {
-        s.parse::<isize>()
-    })(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::solve_with_dimensions;
-    use indoc::indoc;
-
-    #[test]
-    fn test_solve() {
-        let example_input = indoc! {"
-            p=0,4 v=3,-3
-            p=6,3 v=-1,-3
-            p=10,3 v=-1,2
-            p=2,0 v=2,-1
-            p=0,0 v=1,3
-            p=3,0 v=-2,-2
-            p=7,6 v=-1,-3
-            p=3,0 v=-1,-2
-            p=9,3 v=2,3
-            p=7,3 v=-1,2
-            p=2,4 v=2,-3
-            p=9,5 v=-3,-3
-        "};
-        assert_eq!(
-            solve_with_dimensions(example_input, [11, 7], [4, 4]),
-            (Some(12), None)
-        );
-    }
-}
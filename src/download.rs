@@ -0,0 +1,170 @@
+use crate::client::{Client, FetchError};
+use crate::error::{Error, Result};
+use crate::runner::{self, DayRange};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight US Eastern. December is always outside daylight saving time, so that
+/// is a fixed UTC-5.
+const UNLOCK_OFFSET_HOURS: u64 = 5;
+
+/// Number of days since 1970-01-01 of the given date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// When the puzzle of the given day unlocks.
+pub fn unlock_time(year: usize, day: usize) -> SystemTime {
+    let days = days_from_civil(year as u64, 12, day as u64);
+    UNIX_EPOCH + Duration::from_secs(days * 86400 + UNLOCK_OFFSET_HOURS * 3600)
+}
+
+/// The days of `year` whose puzzles are unlocked at `now`.
+pub fn unlocked_days(year: usize, now: SystemTime) -> Vec<usize> {
+    DayRange::ALL
+        .iter()
+        .filter(|&day| unlock_time(year, day) <= now)
+        .collect()
+}
+
+/// Writes a downloaded input to `inputs/YYYY/dayNN.txt`.
+pub fn save_input(year: usize, day: usize, input: &str) -> Result<()> {
+    let input_path = runner::input_path(year, day);
+    if let Some(dir) = Path::new(&input_path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&input_path, input).map_err(|err| Error::new(format!("{}: {}", input_path, err)))
+}
+
+/// Fetches inputs while keeping a minimum delay between requests, retrying transient failures
+/// with exponential backoff.
+pub struct Downloader {
+    client: Client,
+    pub min_delay: Duration,
+    pub retries: u32,
+    last_request: Option<Instant>,
+}
+
+impl Downloader {
+    pub fn new(client: Client, min_delay: Duration) -> Self {
+        Self {
+            client,
+            min_delay,
+            retries: 3,
+            last_request: None,
+        }
+    }
+
+    fn wait(&mut self) {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.min_delay.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    pub fn input(&mut self, year: usize, day: usize) -> Result<String, FetchError> {
+        let mut backoff = self.min_delay.max(Duration::from_secs(1));
+        let mut attempt = 0;
+        loop {
+            self.wait();
+            match self.client.input(year, day) {
+                Err(err) if err.is_transient() && attempt < self.retries => {
+                    attempt += 1;
+                    eprintln!(
+                        "Day {:02}: {}, retrying in {:?} ({}/{})",
+                        day, err, backoff, attempt, self.retries
+                    );
+                    thread::sleep(backoff);
+                    self.last_request = None;
+                    backoff *= 2;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DownloadSummary {
+    pub fetched: Vec<usize>,
+    pub cached: Vec<usize>,
+    pub failed: Vec<(usize, Error)>,
+}
+
+impl Display for DownloadSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let failed = self.failed.iter().map(|(day, _)| *day).collect::<Vec<_>>();
+        for (label, days) in [
+            ("Fetched", &self.fetched),
+            ("Cached", &self.cached),
+            ("Failed", &failed),
+        ] {
+            let list = days.iter().map(usize::to_string).collect::<Vec<_>>();
+            writeln!(f, "{}: {} ({})", label, days.len(), list.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Downloads the inputs of `days` that are not in `inputs/` yet, or all of them if `force` is set.
+pub fn download_all(
+    downloader: &mut Downloader,
+    year: usize,
+    days: &[usize],
+    force: bool,
+) -> DownloadSummary {
+    let mut summary = DownloadSummary::default();
+    for &day in days {
+        if !force && Path::new(&runner::input_path(year, day)).exists() {
+            summary.cached.push(day);
+            continue;
+        }
+
+        let result = downloader
+            .input(year, day)
+            .map_err(Error::from)
+            .and_then(|input| save_input(year, day, &input));
+        match result {
+            Ok(()) => {
+                println!(
+                    "Day {:02}: downloaded {}",
+                    day,
+                    runner::input_path(year, day)
+                );
+                summary.fetched.push(day);
+            }
+            Err(err) => {
+                println!("Day {:02}: {}", day, err);
+                summary.failed.push((day, err.with_day(day)));
+            }
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unlocked_days;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_unlocked_days() {
+        // 2024-12-05 00:00 EST.
+        let day_5 = UNIX_EPOCH + Duration::from_secs(1733374800);
+        assert_eq!(unlocked_days(2024, day_5), (1..=5).collect::<Vec<_>>());
+        assert_eq!(
+            unlocked_days(2024, day_5 - Duration::from_secs(1)),
+            (1..=4).collect::<Vec<_>>()
+        );
+        assert!(unlocked_days(2025, day_5).is_empty());
+        assert_eq!(unlocked_days(2023, day_5).len(), 25);
+    }
+}
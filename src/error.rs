@@ -0,0 +1,155 @@
+use nom::IResult;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A 1-based line and column in a puzzle input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Finds the position of the byte at `offset` in `input`.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let before = &input.as_bytes()[..offset.min(input.len())];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+
+        Self {
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            column: offset - line_start + 1,
+        }
+    }
+}
+
+/// An error from running the solvers or the CLI. Carries the day and the position in the input
+/// when they are known, so bad input gives a readable diagnostic instead of a panic.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    pub day: Option<usize>,
+    pub position: Option<Position>,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            position: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error at the given 1-based line and column.
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            position: Some(Position { line, column }),
+            ..Self::new(message)
+        }
+    }
+
+    /// Creates an error at the byte `offset` in `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            position: Some(Position::from_offset(input, offset)),
+            ..Self::new(message)
+        }
+    }
+
+    /// Creates an error at the start of `part`, which must be a subslice of `input`.
+    pub fn at_str(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = part.as_ptr() as usize - input.as_ptr() as usize;
+        debug_assert!(offset <= input.len());
+        Self::at_offset(input, offset, message)
+    }
+
+    pub fn with_day(self, day: usize) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// The error without the day, for places that already show which day it belongs to.
+    pub fn detail(&self) -> String {
+        match self.position {
+            Some(Position { line, column }) => {
+                format!("line {}, column {}: {}", line, column, self.message)
+            }
+            None => self.message.clone(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}: ", day)?;
+        }
+        f.write_str(&self.detail())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::new(err.to_string())
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::new(err.to_string())
+    }
+}
+
+/// Parses `token`, a subslice of `input`, reporting its position in `input` on failure.
+pub fn parse_token<T: std::str::FromStr>(input: &str, token: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| Error::at_str(input, token, format!("invalid number '{}'", token)))
+}
+
+/// Runs a nom `parser` on `part`, a subslice of `input`, which it must consume entirely. Reports
+/// where in `input` parsing failed, and that `expected` was expected there.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    part: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    expected: &str,
+) -> Result<T> {
+    let rest = match parser(part) {
+        Ok(("", value)) => return Ok(value),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+        Err(nom::Err::Incomplete(_)) => &part[part.len()..],
+    };
+    Err(Error::at_str(input, rest, format!("expected {}", expected)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_token, Error, Position};
+
+    #[test]
+    fn test_error_position() {
+        let input = "1 2\n3 x\n";
+        assert_eq!(
+            Position::from_offset(input, 6),
+            Position { line: 2, column: 3 }
+        );
+
+        let err = parse_token::<usize>(input, &input[6..7]).unwrap_err();
+        assert_eq!(
+            err.with_day(1).to_string(),
+            "day 1: line 2, column 3: invalid number 'x'"
+        );
+        assert_eq!(Error::new("oops").to_string(), "oops");
+    }
+}
@@ -0,0 +1,234 @@
+//! A C ABI for the solvers, built into the `cdylib`, and the ABI of solver plugins (see
+//! [`crate::plugin`]). The build script generates the C header from this module; it is committed as
+//! `include/aoc.h`.
+//!
+//! Panics never unwind into the caller: the solvers already run on their own threads, and
+//! anything else that panics is reported as `AOC_PANICKED`.
+
+use crate::days::Parts;
+use crate::runner::{self, DayOutcome};
+use crate::years;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+/// The day was solved.
+pub const AOC_OK: i32 = 0;
+/// The day is not implemented.
+pub const AOC_NOT_IMPLEMENTED: i32 = 1;
+/// The solver rejected the input; the reason is in `error`.
+pub const AOC_ERROR: i32 = 2;
+/// The solver panicked; the panic message is in `error`.
+pub const AOC_PANICKED: i32 = 3;
+/// A pointer was null or the input was not UTF-8.
+pub const AOC_INVALID_ARGUMENT: i32 = 4;
+
+/// The result of `aoc_solve`. The strings are NUL-terminated and owned by the library; release
+/// them with `aoc_solution_free`.
+#[repr(C)]
+pub struct AocSolution {
+    /// The answer to part 1, or null if it is not implemented.
+    pub part_1: *mut c_char,
+    /// The answer to part 2, or null if it is not implemented.
+    pub part_2: *mut c_char,
+    /// Why the day could not be solved, or null if it was.
+    pub error: *mut c_char,
+    pub parse_ns: u64,
+    pub part_1_ns: u64,
+    pub part_2_ns: u64,
+    pub elapsed_ns: u64,
+}
+
+impl AocSolution {
+    pub fn empty() -> Self {
+        Self {
+            part_1: ptr::null_mut(),
+            part_2: ptr::null_mut(),
+            error: ptr::null_mut(),
+            parse_ns: 0,
+            part_1_ns: 0,
+            part_2_ns: 0,
+            elapsed_ns: 0,
+        }
+    }
+}
+
+/// Solves a puzzle input like `aoc_solve`, for a day that is known in advance.
+pub type AocSolveFn =
+    unsafe extern "C" fn(input: *const u8, input_len: usize, out: *mut AocSolution) -> i32;
+
+/// Releases the strings of a solution filled in by an `AocSolveFn`.
+pub type AocFreeFn = unsafe extern "C" fn(solution: *mut AocSolution);
+
+/// A solver exported by a plugin.
+#[repr(C)]
+pub struct AocPluginSolver {
+    pub year: u32,
+    pub day: u32,
+    /// Name of the variant, NUL-terminated, to tell it apart from the built-in solver.
+    pub variant: *const c_char,
+    pub solve: AocSolveFn,
+    /// Releases the strings allocated by `solve`, which may use a different allocator.
+    pub free: AocFreeFn,
+}
+
+// SAFETY: The variant name is never written to, so plugins can list their solvers in a `static`.
+unsafe impl Sync for AocPluginSolver {}
+
+/// Version of the plugin ABI. It is part of the name of the registration symbol, so that plugins
+/// built against another version are never called.
+pub const AOC_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Signature of the `aoc_plugin_register_v1` symbol every plugin exports. Returns the plugin's
+/// solvers and writes their number to `len`. The solvers must stay valid while the plugin is
+/// loaded.
+pub type AocPluginRegisterFn = unsafe extern "C" fn(len: *mut usize) -> *const AocPluginSolver;
+
+fn to_c_string(s: impl ToString) -> *mut c_char {
+    // Answers and messages don't contain NUL bytes, but don't fail over one if they do.
+    let s = s.to_string().replace('\0', "");
+    CString::new(s).unwrap().into_raw()
+}
+
+/// Solves `day` of `year` on the `input_len` bytes at `input`, writing the result to `out`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out` to a writable `AocSolution`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    input: *const u8,
+    input_len: usize,
+    out: *mut AocSolution,
+) -> i32 {
+    if input.is_null() || out.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+    out.write(AocSolution::empty());
+    let Ok(input) = std::str::from_utf8(slice::from_raw_parts(input, input_len)) else {
+        return AOC_INVALID_ARGUMENT;
+    };
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        runner::solve_input(year as usize, day as usize, None, &[], input, Parts::Both)
+    }));
+    let out = &mut *out;
+    match outcome {
+        Ok(DayOutcome::Solved(solution)) => {
+            out.parse_ns = solution.parse_time.as_nanos() as u64;
+            out.part_1_ns = solution.part_1_time.unwrap_or_default().as_nanos() as u64;
+            out.part_2_ns = solution.part_2_time.unwrap_or_default().as_nanos() as u64;
+            out.elapsed_ns = solution.total_time().as_nanos() as u64;
+            out.part_1 = solution.part_1.map_or(ptr::null_mut(), to_c_string);
+            out.part_2 = solution.part_2.map_or(ptr::null_mut(), to_c_string);
+            AOC_OK
+        }
+        Ok(DayOutcome::NotImplemented | DayOutcome::NoInput) => AOC_NOT_IMPLEMENTED,
+        Ok(DayOutcome::Panicked(message)) => {
+            out.error = to_c_string(message);
+            AOC_PANICKED
+        }
+        Ok(outcome) => {
+            out.error = to_c_string(outcome.failure().unwrap_or_default());
+            AOC_ERROR
+        }
+        Err(_) => AOC_PANICKED,
+    }
+}
+
+/// Releases the strings of a solution filled in by `aoc_solve`, and resets them to null.
+///
+/// # Safety
+///
+/// `solution` must be null or point to a solution filled in by `aoc_solve`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solution_free(solution: *mut AocSolution) {
+    let Some(solution) = solution.as_mut() else {
+        return;
+    };
+    for s in [
+        &mut solution.part_1,
+        &mut solution.part_2,
+        &mut solution.error,
+    ] {
+        if !s.is_null() {
+            drop(CString::from_raw(*s));
+            *s = ptr::null_mut();
+        }
+    }
+}
+
+/// Writes up to `capacity` of the implemented days of `year` to `out`, and returns how many
+/// there are in total.
+///
+/// # Safety
+///
+/// `out` must point to `capacity` writable values, or be null if `capacity` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_days(year: u32, out: *mut u32, capacity: usize) -> usize {
+    let days = panic::catch_unwind(|| years::days(year as usize)).unwrap_or_default();
+    if !out.is_null() {
+        for (i, &day) in days.iter().take(capacity).enumerate() {
+            out.add(i).write(day as u32);
+        }
+    }
+    days.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aoc_days, aoc_solution_free, aoc_solve, AocSolution, AOC_ERROR, AOC_OK};
+    use std::env;
+    use std::ffi::CStr;
+    use std::fs;
+
+    #[test]
+    fn test_aoc_solve() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let mut solution = AocSolution::empty();
+        unsafe {
+            assert_eq!(
+                aoc_solve(2024, 1, input.as_ptr(), input.len(), &mut solution),
+                AOC_OK
+            );
+            assert_eq!(CStr::from_ptr(solution.part_1).to_str(), Ok("11"));
+            assert_eq!(CStr::from_ptr(solution.part_2).to_str(), Ok("31"));
+            assert!(solution.error.is_null());
+            aoc_solution_free(&mut solution);
+            assert!(solution.part_1.is_null());
+
+            assert_eq!(
+                aoc_solve(2024, 1, "x".as_ptr(), 1, &mut solution),
+                AOC_ERROR
+            );
+            assert!(!solution.error.is_null());
+            aoc_solution_free(&mut solution);
+
+            let mut days = [0; 2];
+            assert!(aoc_days(2024, days.as_mut_ptr(), days.len()) > 2);
+            assert_eq!(days, [1, 2]);
+        }
+    }
+
+    /// Run with `AOC_UPDATE_HEADER=1` to update `include/aoc.h`.
+    #[test]
+    fn test_header() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+        assert!(
+            !generated.is_empty(),
+            "the build script could not generate aoc.h"
+        );
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h");
+        if env::var_os("AOC_UPDATE_HEADER").is_some() {
+            fs::write(path, generated).unwrap();
+        }
+        assert!(
+            fs::read_to_string(path).unwrap() == generated,
+            "include/aoc.h is out of date, run the tests with AOC_UPDATE_HEADER=1 to update it"
+        );
+    }
+}
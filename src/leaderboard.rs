@@ -0,0 +1,191 @@
+use crate::download::unlock_time;
+use crate::error::{Error, Result};
+use crate::util::table::Table;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::time::UNIX_EPOCH;
+
+/// A private leaderboard, as served at `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: u64,
+    #[serde(default)]
+    pub last_star_ts: u64,
+    /// When each star was earned, by day and then by part.
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    /// The member's name, or how the site shows members without one.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the member earned the star for the given day and part, as a Unix timestamp.
+    pub fn star_ts(&self, day: usize, part: u8) -> Option<u64> {
+        let star = self
+            .completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())?;
+        Some(star.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|err| Error::new(format!("invalid leaderboard: {}", err)))
+    }
+
+    /// The members from first to last place: by score, then by stars, then by who got their last
+    /// star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| {
+            (
+                u64::MAX - member.local_score,
+                usize::MAX - member.stars,
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+
+    /// The year of the event, if unlock times can be computed for it: those are measured from the
+    /// Unix epoch.
+    fn year(&self) -> Option<usize> {
+        self.event.parse().ok().filter(|&year| year >= 1970)
+    }
+}
+
+/// Formats a number of seconds as `HH:MM:SS`, prefixed with the number of days if any.
+fn format_duration(secs: u64) -> String {
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+    match days {
+        0 => time,
+        days => format!("{}d {}", days, time),
+    }
+}
+
+impl Display for Leaderboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ranking = self.ranking();
+
+        writeln!(f, "Leaderboard {}", self.event)?;
+        writeln!(f)?;
+        let mut table = Table::new(["Rank", "Name", "Score", "Stars"]);
+        table.right_aligned = [true, false, true, true];
+        for (rank, member) in ranking.iter().enumerate() {
+            table.rows.push([
+                (rank + 1).to_string(),
+                member.display_name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+            ]);
+        }
+        table.write(f)?;
+
+        // Completion times are shown relative to when the puzzle unlocked.
+        let Some(year) = self.year() else {
+            return Ok(());
+        };
+        for day in 1..=25 {
+            let unlock = unlock_time(year, day)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let mut members = ranking
+                .iter()
+                .filter_map(|member| {
+                    let part_1 = member.star_ts(day, 1)?;
+                    Some((member, part_1, member.star_ts(day, 2)))
+                })
+                .collect::<Vec<_>>();
+            if members.is_empty() {
+                continue;
+            }
+            members.sort_by_key(|&(_, part_1, part_2)| (part_2.unwrap_or(u64::MAX), part_1));
+
+            writeln!(f)?;
+            writeln!(f, "Day {}", day)?;
+            writeln!(f)?;
+            let mut table = Table::new(["Name", "Part 1", "Part 2", "Delta"]);
+            table.right_aligned = [false, true, true, true];
+            for (member, part_1, part_2) in members {
+                let since_unlock = |ts: u64| format_duration(ts.saturating_sub(unlock));
+                table.rows.push([
+                    member.display_name(),
+                    since_unlock(part_1),
+                    part_2.map(since_unlock).unwrap_or_default(),
+                    part_2
+                        .map(|part_2| format_duration(part_2.saturating_sub(part_1)))
+                        .unwrap_or_default(),
+                ]);
+            }
+            table.write(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Leaderboard;
+    use indoc::indoc;
+
+    #[test]
+    fn test_leaderboard() {
+        let leaderboard =
+            Leaderboard::parse(include_str!("../tests/fixtures/leaderboard.json")).unwrap();
+        assert_eq!(
+            leaderboard.to_string(),
+            indoc! {"
+                Leaderboard 2024
+
+                Rank | Name                | Score | Stars
+                -----+---------------------+-------+------
+                   1 | Alice               |     9 |     3
+                   2 | (anonymous user #2) |     4 |     2
+                   3 | Bob                 |     0 |     0
+
+                Day 1
+
+                Name                |   Part 1 |   Part 2 |    Delta
+                --------------------+----------+----------+---------
+                Alice               | 00:05:12 | 00:08:30 | 00:03:18
+                (anonymous user #2) | 00:10:00 | 01:02:05 | 00:52:05
+
+                Day 2
+
+                Name  |      Part 1 | Part 2 | Delta
+                ------+-------------+--------+------
+                Alice | 1d 01:00:00 |        |
+            "}
+        );
+
+        let mut leaderboard = leaderboard;
+        leaderboard.event = "1969".to_string();
+        assert!(!leaderboard.to_string().contains("Day 1"));
+    }
+}
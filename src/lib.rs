@@ -1,4 +1,22 @@
 extern crate core;
 
+pub mod answers;
+pub mod client;
+pub mod config;
 pub mod days;
+pub mod download;
+pub mod error;
+pub mod ffi;
+pub mod leaderboard;
+pub mod params;
+pub mod plugin;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod server;
+pub mod session;
+pub mod submit;
 mod util;
+pub mod watch;
+pub mod years;
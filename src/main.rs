@@ -1,74 +1,623 @@
-use clap::{Parser, Subcommand};
-use homedir::my_home;
+use advent_of_code_2024::answers::{self, Answers, Baseline};
+use advent_of_code_2024::client::Client;
+use advent_of_code_2024::config::{self, Config, Settings};
+use advent_of_code_2024::days::Parts;
+use advent_of_code_2024::download::{self, Downloader};
+use advent_of_code_2024::error::{Error, Result};
+use advent_of_code_2024::leaderboard::Leaderboard;
+use advent_of_code_2024::params::ParamValue;
+use advent_of_code_2024::puzzle;
+use advent_of_code_2024::report::{self, DayReport, Format};
+use advent_of_code_2024::runner::{self, DayOutcome, DayRange, InputSource, Solver, SummaryTable};
+use advent_of_code_2024::scaffold;
+use advent_of_code_2024::server::SolveServer;
+use advent_of_code_2024::session::{self, Session};
+use advent_of_code_2024::submit::{GuessLog, Verdict};
+use advent_of_code_2024::watch::{self, Snapshot, WatchRow, WatchTable, Watcher};
+use advent_of_code_2024::years;
+use clap::{Args, Parser, Subcommand};
 use std::fs;
-use std::time::Instant;
-
-const YEAR: usize = 2024;
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
 
 #[derive(Parser)]
 struct Cli {
+    /// Overrides the settings from `aoc.toml`.
+    #[command(flatten)]
+    settings: Settings,
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    Download { day: usize },
-    Solve { day: usize },
+    /// Download the puzzle input for a day.
+    Download {
+        #[arg(required_unless_present = "all")]
+        day: Option<usize>,
+        /// Download the inputs of all unlocked days that are not in `inputs/` yet.
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Overwrite inputs that were already downloaded.
+        #[arg(long)]
+        force: bool,
+        /// Minimum number of seconds between requests when downloading several days.
+        #[arg(long, default_value_t = 3.0)]
+        delay: f64,
+    },
+    /// Convert a puzzle description to Markdown and extract its code blocks as example inputs.
+    Describe {
+        day: usize,
+        /// Convert this saved puzzle page instead of fetching it.
+        #[arg(long, value_name = "PATH")]
+        html: Option<PathBuf>,
+    },
+    /// Create a new day module from the `dayN.rs` template.
+    New {
+        day: usize,
+        /// Also download the puzzle input.
+        #[arg(long)]
+        download: bool,
+    },
+    Solve {
+        /// Day or range of days to solve, e.g. `5`, `3..9` or `3..=9`.
+        #[arg(required_unless_present = "all")]
+        days: Option<DayRange>,
+        /// Solve all implemented days.
+        #[arg(long, conflicts_with = "days")]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Only solve this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run this named variant of the solver, built in (e.g. `naive`) or from a plugin.
+        #[arg(long)]
+        variant: Option<String>,
+        /// Set a puzzle parameter of the day, e.g. `width=11` to solve the example of day 14. Can be
+        /// repeated.
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<ParamValue>,
+        /// Number of days to solve in parallel.
+        #[arg(long, short, default_value = "1")]
+        jobs: NonZeroUsize,
+    },
+    /// Record the current answers for a day as known-good.
+    Accept {
+        day: usize,
+        /// Only record this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Check every day against its recorded answers, and that its variants agree with it.
+    Verify,
+    /// Show a private leaderboard.
+    Leaderboard {
+        id: u64,
+        /// Show this saved leaderboard JSON instead of fetching it.
+        #[arg(long, value_name = "PATH")]
+        json: Option<PathBuf>,
+    },
+    /// Manage the session cookie used to talk to the server.
+    #[command(subcommand)]
+    Session(SessionCommand),
+    /// Solve a part and submit the answer.
+    Submit {
+        day: usize,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Serve the solvers over HTTP on localhost: `GET /days` and `POST /solve/DAY`.
+    Serve {
+        /// Port to listen on, or 0 for any free port.
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Re-run a day on its example and puzzle inputs whenever they change.
+    Watch {
+        day: usize,
+        /// File with the expected example answers, as `part 1: ...` and `part 2: ...` lines.
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Number of seconds between checks for changes.
+        #[arg(long, default_value_t = 0.5)]
+        interval: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum SessionCommand {
+    /// Store the session cookie, readable only by the current user.
+    Set {
+        /// The value of the `session` cookie; read from stdin if omitted.
+        token: Option<String>,
+    },
+    /// Check the session against the server and show the account it belongs to.
+    Whoami,
+    /// Remove the stored session cookie.
+    Clear,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file instead, or from stdin if `-`.
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Read the example input from `inputs/YYYY/dayNN.example.txt` instead.
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::Path(path.clone()),
+            None if self.example => InputSource::Example,
+            None => InputSource::Puzzle,
+        }
+    }
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    match run(cli) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode> {
+    config::init(Config::load(cli.settings)?);
+    let config = config::get();
+
     match cli.command {
-        Commands::Download { day } => {
-            let input_url = format!("https://adventofcode.com/{}/day/{}/input", YEAR, day);
+        Commands::Download {
+            day: Some(day),
+            force,
+            ..
+        } => download(day, force)?,
+
+        Commands::Download { force, delay, .. } => {
+            let client = Client::new(&config.base_url, Some(session::load()?))?;
+            let delay = Duration::try_from_secs_f64(delay)
+                .map_err(|_| Error::new(format!("invalid delay: {}", delay)))?;
+            let mut downloader = Downloader::new(client, delay);
+            let days = download::unlocked_days(config.year, SystemTime::now());
+            let summary = download::download_all(&mut downloader, config.year, &days, force);
+
+            println!();
+            print!("{}", summary);
+            if !summary.failed.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+
+        Commands::Describe { day, html } => {
+            let html = match html {
+                Some(path) => fs::read_to_string(&path)
+                    .map_err(|err| Error::new(format!("{}: {}", path.display(), err)))?,
+                None => Client::new(&config.base_url, session::load().ok())?
+                    .puzzle(config.year, day)
+                    .map_err(|err| Error::from(err).with_day(day))?,
+            };
+            for path in puzzle::parse_description(&html).save(config.year, day)? {
+                println!("Wrote {}", path);
+            }
+        }
+
+        Commands::New {
+            day,
+            download: download_input,
+        } => {
+            if !DayRange::ALL.iter().contains(&day) {
+                return Err(Error::new(format!(
+                    "day {} is not between {} and {}",
+                    day,
+                    DayRange::ALL.first,
+                    DayRange::ALL.last
+                )));
+            }
+            let path = scaffold::module_path(config.year, day);
+            scaffold::new_day(config.year, day)
+                .map_err(|err| Error::new(format!("{}: {}", path, err)))?;
+            println!("Created {}", path);
+
+            if download_input {
+                download(day, false)?;
+            }
+        }
 
-            let session_path = {
-                let mut path = my_home().unwrap().unwrap();
-                path.push(".adventofcode.session");
-                path
+        Commands::Solve {
+            days,
+            all,
+            input,
+            part,
+            variant,
+            params,
+            jobs,
+        } => {
+            let format = config.format;
+            let source = input.source();
+            let parts = match part {
+                None => Parts::Both,
+                Some(1) => Parts::Part1,
+                Some(_) => Parts::Part2,
             };
-            let session = fs::read_to_string(session_path).unwrap().trim().to_string();
+            let single = days.is_some_and(DayRange::is_single);
+            if !params.is_empty() && !single {
+                // Parameters are declared per day, so they only make sense for one.
+                return Err(Error::new("--param can only be used with a single day"));
+            }
+            let days = match days {
+                Some(range) if range.is_single() => vec![range.first],
+                Some(_) if !source.is_per_day() => {
+                    return Err(Error::new("--input can only be used with a single day"));
+                }
+                Some(range) => range.days(config.year, variant.as_deref()),
+                None => {
+                    debug_assert!(all);
+                    DayRange::ALL.days(config.year, variant.as_deref())
+                }
+            };
+
+            if let Some(variant) = &variant {
+                if !days
+                    .iter()
+                    .any(|&day| Solver::find(config.year, day, Some(variant)).is_some())
+                {
+                    return Err(Error::new(format!("no solver has variant '{}'", variant)));
+                }
+            }
+            if single && format == Format::Text {
+                let run = runner::run_day(
+                    config.year,
+                    days[0],
+                    variant.as_deref(),
+                    &params,
+                    &source,
+                    parts,
+                );
+                return print_day(run, &source);
+            }
 
-            let client = reqwest::blocking::Client::new();
-            let response = client
-                .get(input_url)
-                .header("cookie", format!("session={}", session))
-                .send()
-                .unwrap();
+            let runs = runner::run_days(
+                config.year,
+                &days,
+                variant.as_deref(),
+                &params,
+                &source,
+                parts,
+                jobs.get(),
+            );
 
-            if response.status() == reqwest::StatusCode::OK {
-                let input_path = format!("inputs/day{:02}.txt", day);
-                let input = response.text().unwrap();
-                fs::write(&input_path, input).unwrap();
-            } else {
-                println!("couldn't fetch adventofcode input");
+            let reports = || {
+                runs.iter()
+                    .map(|run| DayReport::new(run, &source))
+                    .collect::<Vec<_>>()
+            };
+            match format {
+                Format::Text => print!("{}", SummaryTable(&runs)),
+                Format::Json => {
+                    report::write_json(io::stdout(), &reports())?;
+                    println!();
+                }
+                Format::Csv => report::write_csv(io::stdout(), &reports())?,
+            }
+
+            if runs.iter().any(|run| run.outcome.failure().is_some()) {
+                return Ok(ExitCode::FAILURE);
             }
         }
 
-        Commands::Solve { day } => {
-            let input_path = format!("inputs/day{:02}.txt", day);
+        Commands::Accept { day, part } => {
+            let input_path = runner::input_path(config.year, day);
             let input = fs::read_to_string(&input_path)
-                .unwrap_or_else(|_| panic!("Input file not found: {}", input_path));
-            let solve = advent_of_code_2024::days::solver(day)
-                .unwrap_or_else(|| panic!("Day {}: not yet implemented", day));
+                .map_err(|err| Error::new(format!("{}: {}", input_path, err)))?;
+            let solution =
+                match runner::solve_input(config.year, day, None, &[], &input, Parts::Both) {
+                    DayOutcome::Solved(solution) => solution,
+                    DayOutcome::Error(err) => return Err(err),
+                    outcome @ (DayOutcome::Panicked(_) | DayOutcome::TimedOut(_)) => {
+                        return Err(Error::new(outcome.failure().unwrap_or_default()).with_day(day))
+                    }
+                    _ => return Err(Error::new("not yet implemented").with_day(day)),
+                };
+
+            let mut answers = match Answers::load(config.year, day)? {
+                Some(answers) if answers.input_hash == answers::hash_input(&input) => answers,
+                _ => Answers::new(&input),
+            };
+            for (p, solution) in [(1, solution.part_1), (2, solution.part_2)] {
+                if part.is_some_and(|part| part != p) {
+                    continue;
+                }
+                if let Some(solution) = solution {
+                    answers.set_part(p, &solution);
+                    println!("Part {}: {}", p, solution);
+                }
+            }
+            answers.save(config.year, day)?;
+        }
 
-            let start = Instant::now();
-            let (part_1, part_2) = solve(&input);
-            let elapsed = start.elapsed();
+        Commands::Leaderboard { id, json } => {
+            let json = match json {
+                Some(path) => fs::read_to_string(&path)
+                    .map_err(|err| Error::new(format!("{}: {}", path.display(), err)))?,
+                None => Client::new(&config.base_url, Some(session::load()?))?
+                    .leaderboard(config.year, id)?,
+            };
+            print!("{}", Leaderboard::parse(&json)?);
+        }
+
+        Commands::Session(SessionCommand::Set { token }) => {
+            let token = match token {
+                Some(token) => token,
+                None => {
+                    let mut token = String::new();
+                    io::stdin().read_to_string(&mut token)?;
+                    token
+                }
+            };
+            let session = Session::new(&token)?;
+            let path = session::save(&session)?;
+            println!("Stored session {} in {}", session, path.display());
+        }
 
-            println!("Day {}", day);
-            match part_1 {
-                Some(part_1) => println!("Part 1: {}", part_1),
-                None => println!("Part 1: not yet implemented"),
+        Commands::Session(SessionCommand::Whoami) => {
+            let (session, source) = session::load_with_source()?;
+            let name = Client::new(&config.base_url, Some(session.clone()))?.whoami(config.year)?;
+            match name {
+                Some(name) => println!(
+                    "Logged in as {} (session {} from {})",
+                    name, session, source
+                ),
+                None => {
+                    return Err(Error::new(format!(
+                        "session {} from {} is not logged in",
+                        session, source
+                    )))
+                }
             }
-            match part_2 {
-                Some(part_2) => println!("Part 2: {}", part_2),
-                None => println!("Part 2: not yet implemented"),
+        }
+
+        Commands::Session(SessionCommand::Clear) => match session::clear()? {
+            true => println!("Removed {}", session::session_path()?.display()),
+            false => println!("No session stored"),
+        },
+
+        Commands::Serve { port } => {
+            let server = SolveServer::start(config.year, port)?;
+            println!("Serving the {} solvers on {}", config.year, server.url());
+            server.wait();
+        }
+
+        Commands::Submit { day, part } => return submit(day, part),
+
+        Commands::Watch {
+            day,
+            answers,
+            interval,
+        } => {
+            let interval = Duration::try_from_secs_f64(interval)
+                .map_err(|_| Error::new(format!("invalid interval: {}", interval)))?;
+            return watch(day, answers.as_deref(), interval);
+        }
+
+        Commands::Verify => {
+            let mut failed = false;
+            for day in years::days(config.year) {
+                let baseline = Baseline::new(config.year, day);
+                let verification = answers::verify_day(&baseline);
+                println!("Day {:02}: {}", day, verification);
+                failed |= verification.is_failure();
+
+                for variant in Solver::variants(config.year, day) {
+                    let verification = answers::verify_variant(&baseline, &variant);
+                    println!("Day {:02}/{}: {}", day, variant, verification);
+                    failed |= verification.is_failure();
+                }
             }
 
-            println!("Elapsed: {:?}", elapsed);
+            if failed {
+                return Ok(ExitCode::FAILURE);
+            }
         }
     }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(day: usize, part: u8) -> Result<ExitCode> {
+    let config = config::get();
+    let parts = if part == 1 {
+        Parts::Part1
+    } else {
+        Parts::Part2
+    };
+    let run = runner::run_day(config.year, day, None, &[], &InputSource::Puzzle, parts);
+    let solution = match run.outcome {
+        DayOutcome::Solved(solution) => solution,
+        DayOutcome::Error(err) => return Err(err),
+        outcome @ (DayOutcome::Panicked(_) | DayOutcome::TimedOut(_)) => {
+            return Err(Error::new(outcome.failure().unwrap_or_default()).with_day(day))
+        }
+        DayOutcome::NoInput => {
+            let input_path = runner::input_path(config.year, day);
+            return Err(Error::new(format!("no input: {}", input_path)).with_day(day));
+        }
+        DayOutcome::NotImplemented => return Err(Error::new("not yet implemented").with_day(day)),
+    };
+    let answer = match if part == 1 {
+        solution.part_1
+    } else {
+        solution.part_2
+    } {
+        Some(answer) => answer.to_string(),
+        None => {
+            return Err(Error::new(format!("part {} is not yet implemented", part)).with_day(day))
+        }
+    };
+
+    let mut log = GuessLog::load(config.year, day)?;
+    log.check(part, &answer)
+        .map_err(|err| Error::new(format!("not submitting: {}", err)).with_day(day))?;
+
+    let client = Client::new(&config.base_url, Some(session::load()?))?;
+    let response = client
+        .submit(config.year, day, part, &answer)
+        .map_err(|err| Error::from(err).with_day(day))?;
+    let verdict = Verdict::from_response(&response).map_err(|err| err.with_day(day))?;
+
+    log.record(part, &answer, verdict.clone());
+    log.save(config.year, day)?;
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+
+    Ok(match verdict {
+        Verdict::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+fn download(day: usize, force: bool) -> Result<()> {
+    let config = config::get();
+    let input_path = runner::input_path(config.year, day);
+    if !force && Path::new(&input_path).exists() {
+        return Err(Error::new(format!(
+            "{} already exists, use --force to overwrite it",
+            input_path
+        ))
+        .with_day(day));
+    }
+
+    let client = Client::new(&config.base_url, Some(session::load()?))?;
+    let input = client
+        .input(config.year, day)
+        .map_err(|err| Error::from(err).with_day(day))?;
+
+    download::save_input(config.year, day, &input)?;
+    println!("Downloaded {}", input_path);
+    Ok(())
+}
+
+/// Runs a day on its example and puzzle inputs, and again whenever one of them changes. When the
+/// day's source changes, rebuilds and restarts the watch with the new solver.
+fn watch(day: usize, answers: Option<&Path>, interval: Duration) -> Result<ExitCode> {
+    let config = config::get();
+    let mut previous: Vec<(String, Snapshot)> = vec![];
+    loop {
+        // The code blocks `describe` extracted can come and go between runs, so they are
+        // looked up again each time.
+        let blocks = puzzle::example_block_paths(config.year, day);
+        let mut sources = vec![("example".to_string(), InputSource::Example)];
+        for (k, path) in &blocks {
+            sources.push((format!("example-{}", k), InputSource::Path(path.clone())));
+        }
+        sources.push(("puzzle".to_string(), InputSource::Puzzle));
+        let mut inputs = Watcher::new(
+            [
+                runner::example_path(config.year, day),
+                runner::input_path(config.year, day),
+            ]
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(blocks.iter().map(|(_, path)| path.clone()))
+            .chain(answers.map(Path::to_path_buf)),
+        );
+
+        let expected = answers.map(watch::read_expected).transpose()?;
+        let snapshots = sources
+            .iter()
+            .map(|(label, source)| {
+                let run = runner::run_day(config.year, day, None, &[], source, Parts::Both);
+                (label.clone(), Snapshot::from(&run))
+            })
+            .collect::<Vec<_>>();
+        let rows = snapshots
+            .iter()
+            .map(|(label, current)| WatchRow {
+                label,
+                current,
+                previous: previous
+                    .iter()
+                    .find(|(previous, _)| previous == label)
+                    .map(|(_, snapshot)| snapshot),
+                expected: expected.as_ref().filter(|_| label == "example"),
+            })
+            .collect::<Vec<_>>();
+        println!("Day {}", day);
+        print!("{}", WatchTable(&rows));
+        previous = snapshots;
+
+        loop {
+            thread::sleep(interval);
+            if puzzle::example_block_paths(config.year, day) != blocks {
+                println!();
+                println!("Example files changed");
+                break;
+            }
+
+            let changed = inputs.poll();
+            if !changed.is_empty() {
+                let changed = changed
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>();
+                println!();
+                println!("Changed: {}", changed.join(", "));
+                break;
+            }
+        }
+    }
+}
+
+/// Prints a single day's results, failing if it could not be solved.
+fn print_day(run: runner::DayRun, source: &InputSource) -> Result<ExitCode> {
+    println!("Day {}", run.day);
+    match run.outcome {
+        DayOutcome::NotImplemented => {
+            return Err(Error::new("not yet implemented").with_day(run.day))
+        }
+        DayOutcome::NoInput => {
+            return Err(
+                Error::new(format!("no input: {}", source.describe(run.year, run.day)))
+                    .with_day(run.day),
+            )
+        }
+        DayOutcome::Error(err) => return Err(err),
+        outcome @ (DayOutcome::Panicked(_) | DayOutcome::TimedOut(_)) => {
+            return Err(Error::new(outcome.failure().unwrap_or_default()).with_day(run.day))
+        }
+        DayOutcome::Solved(solution) => {
+            println!("Parse: {:?}", solution.parse_time);
+            for (part, time, n) in [
+                (&solution.part_1, solution.part_1_time, 1),
+                (&solution.part_2, solution.part_2_time, 2),
+            ] {
+                match time {
+                    Some(time) => {
+                        println!(
+                            "Part {}: {} ({:?})",
+                            n,
+                            runner::format_part(part, Some(time)),
+                            time
+                        )
+                    }
+                    None => println!("Part {}: skipped", n),
+                }
+            }
+
+            println!("Elapsed: {:?}", solution.total_time());
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
 }
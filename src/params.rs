@@ -0,0 +1,124 @@
+//! Puzzle parameters: values that belong to a puzzle rather than to its solution, like the size of
+//! the room in day 14, which is smaller in the examples. Each day declares its parameters with
+//! their defaults in [`Day::PARAMS`](crate::days::Day::PARAMS), and they can be set from the
+//! command line with `--param name=value`.
+
+use crate::error::{Error, Result};
+use std::str::FromStr;
+
+/// A puzzle parameter declared by a day.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64) -> Self {
+        Self { name, default }
+    }
+}
+
+/// A value for a puzzle parameter, written as `name=value`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParamValue {
+    pub name: String,
+    pub value: i64,
+}
+
+impl FromStr for ParamValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected 'name=value', got '{}'", s))?;
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("expected an integer for '{}', got '{}'", name, value))?;
+        Ok(Self {
+            name: name.trim().to_string(),
+            value,
+        })
+    }
+}
+
+/// The values of a day's parameters.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    /// The declared parameters with their defaults.
+    pub fn defaults(declared: &[Param]) -> Self {
+        Self {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    /// The declared parameters with their defaults, overridden by `values`.
+    pub fn new(declared: &[Param], values: &[ParamValue]) -> Result<Self> {
+        let mut params = Self::defaults(declared);
+        for value in values {
+            let Some(param) = params
+                .values
+                .iter_mut()
+                .find(|(name, _)| *name == value.name)
+            else {
+                return Err(Error::new(match declared {
+                    [] => format!("unknown parameter '{}', there are none", value.name),
+                    _ => format!(
+                        "unknown parameter '{}', expected one of: {}",
+                        value.name,
+                        declared
+                            .iter()
+                            .map(|param| param.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                }));
+            };
+            param.1 = value.value;
+        }
+        Ok(params)
+    }
+
+    /// The value of a declared parameter, converted to the type the day works with.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T> {
+        let &(_, value) = self
+            .values
+            .iter()
+            .find(|(param, _)| *param == name)
+            .ok_or_else(|| Error::new(format!("parameter '{}' is not declared", name)))?;
+        T::try_from(value)
+            .map_err(|_| Error::new(format!("parameter '{}' is out of range: {}", name, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, ParamValue, Params};
+
+    #[test]
+    fn test_params() {
+        let declared = [Param::new("width", 101), Param::new("height", 103)];
+        let width = "width=11".parse::<ParamValue>().unwrap();
+        let params = Params::new(&declared, &[width]).unwrap();
+        assert_eq!(params.get::<usize>("width"), Ok(11));
+        assert_eq!(params.get::<isize>("height"), Ok(103));
+
+        let negative = "height=-1".parse().unwrap();
+        let params = Params::new(&declared, &[negative]).unwrap();
+        assert!(params.get::<usize>("height").is_err());
+
+        assert!("width".parse::<ParamValue>().is_err());
+        assert!("width=x".parse::<ParamValue>().is_err());
+        let depth = "depth=3".parse().unwrap();
+        assert!(Params::new(&declared, &[depth]).is_err());
+    }
+}
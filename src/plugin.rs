@@ -0,0 +1,221 @@
+//! Solvers loaded at runtime from shared libraries in the plugins directory, so that alternative
+//! solutions can be tried out without adding them to `src/years/`.
+//!
+//! A plugin exports `aoc_plugin_register_v1` (see [`AocPluginRegisterFn`]), which lists its
+//! solvers as `(year, day, variant, solve fn)`. The C declarations are in `include/aoc.h`, and
+//! `examples/plugin.rs` is a plugin written in Rust.
+
+use crate::config;
+use crate::days::{PartSolution, Parts, TimedSolution};
+use crate::error::{Error, Result};
+use crate::ffi::{
+    AocFreeFn, AocPluginRegisterFn, AocSolution, AocSolveFn, AOC_NOT_IMPLEMENTED, AOC_OK,
+    AOC_PLUGIN_ABI_VERSION,
+};
+use libloading::Library;
+use std::env::consts::DLL_EXTENSION;
+use std::ffi::{c_char, CStr};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::slice;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// Name of the registration symbol of the current plugin ABI version.
+pub const REGISTER_SYMBOL: &str = "aoc_plugin_register_v1";
+
+/// A solver from a plugin.
+pub struct PluginSolver {
+    pub year: usize,
+    pub day: usize,
+    pub variant: String,
+    /// The plugin the solver is from.
+    pub path: PathBuf,
+    solve: AocSolveFn,
+    free: AocFreeFn,
+    // Keeps `solve` and `free` loaded.
+    _library: Arc<Library>,
+}
+
+impl PluginSolver {
+    pub fn solve(&self, input: &str, parts: Parts) -> Result<TimedSolution> {
+        let mut out = AocSolution::empty();
+        // SAFETY: The plugin's functions follow the ABI of `AocSolveFn` and `AocFreeFn`, and the
+        // strings are copied before `free` releases them.
+        let (status, part_1, part_2, error) = unsafe {
+            let status = (self.solve)(input.as_ptr(), input.len(), &mut out);
+            let strings = (
+                take_string(out.part_1),
+                take_string(out.part_2),
+                take_string(out.error),
+            );
+            (self.free)(&mut out);
+            (status, strings.0, strings.1, strings.2)
+        };
+
+        match status {
+            AOC_OK => {
+                let part = |part: u8, answer: Option<String>, ns: u64| match parts.includes(part) {
+                    true => (
+                        answer.map(PartSolution::from),
+                        Some(Duration::from_nanos(ns)),
+                    ),
+                    false => (None, None),
+                };
+                let (part_1, part_1_time) = part(1, part_1, out.part_1_ns);
+                let (part_2, part_2_time) = part(2, part_2, out.part_2_ns);
+                Ok(TimedSolution {
+                    part_1,
+                    part_2,
+                    parse_time: Duration::from_nanos(out.parse_ns),
+                    part_1_time,
+                    part_2_time,
+                })
+            }
+            AOC_NOT_IMPLEMENTED => Err(Error::new("not implemented by the plugin")),
+            status => {
+                Err(Error::new(error.unwrap_or_else(|| {
+                    format!("plugin failed with status {}", status)
+                })))
+            }
+        }
+    }
+}
+
+/// Copies a string returned by a plugin.
+///
+/// # Safety
+///
+/// `s` must be null or a NUL-terminated string.
+unsafe fn take_string(s: *const c_char) -> Option<String> {
+    (!s.is_null()).then(|| CStr::from_ptr(s).to_string_lossy().into_owned())
+}
+
+/// Loads the solvers of a plugin.
+pub fn load(path: &Path) -> Result<Vec<PluginSolver>> {
+    let error = |message: String| Error::new(format!("{}: {}", path.display(), message));
+
+    // SAFETY: Plugins are trusted like the built-in solvers; loading one runs its initializers.
+    let library = unsafe { Library::new(path) }.map_err(|err| error(err.to_string()))?;
+    // SAFETY: The symbol's name includes the ABI version, which fixes its signature.
+    let register = unsafe { library.get::<AocPluginRegisterFn>(REGISTER_SYMBOL.as_bytes()) }
+        .map(|register| *register)
+        .map_err(|_| {
+            error(format!(
+                "not a plugin for ABI version {}, it does not export `{}`",
+                AOC_PLUGIN_ABI_VERSION, REGISTER_SYMBOL
+            ))
+        })?;
+
+    let mut len = 0;
+    // SAFETY: The plugin returns `len` solvers, valid while it is loaded.
+    let solvers = unsafe {
+        let solvers = register(&mut len);
+        match solvers.is_null() {
+            true => &[],
+            false => slice::from_raw_parts(solvers, len),
+        }
+    };
+
+    let library = Arc::new(library);
+    solvers
+        .iter()
+        .map(|solver| {
+            // SAFETY: The variant is null or a NUL-terminated string.
+            let variant = unsafe { take_string(solver.variant) }
+                .filter(|variant| !variant.is_empty())
+                .ok_or_else(|| error(format!("day {} has no variant name", solver.day)))?;
+            Ok(PluginSolver {
+                year: solver.year as usize,
+                day: solver.day as usize,
+                variant,
+                path: path.to_path_buf(),
+                solve: solver.solve,
+                free: solver.free,
+                _library: library.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Loads the solvers of every plugin in `dir`, along with the errors of the plugins that could not
+/// be loaded. A missing directory has no plugins.
+pub fn load_dir(dir: &Path) -> (Vec<PluginSolver>, Vec<Error>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return (vec![], vec![]),
+        Err(err) => {
+            return (
+                vec![],
+                vec![Error::new(format!("{}: {}", dir.display(), err))],
+            )
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == DLL_EXTENSION))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut solvers = vec![];
+    let mut errors = vec![];
+    for path in paths {
+        match load(&path) {
+            Ok(plugin_solvers) => solvers.extend(plugin_solvers),
+            Err(err) => errors.push(err),
+        }
+    }
+    (solvers, errors)
+}
+
+static SOLVERS: OnceLock<Vec<PluginSolver>> = OnceLock::new();
+
+/// The solvers of the plugins in the configured directory, loaded on first use. Plugins that fail
+/// to load are reported on stderr and skipped.
+pub fn solvers() -> &'static [PluginSolver] {
+    SOLVERS.get_or_init(|| {
+        let (solvers, errors) = load_dir(&config::get().plugins_dir);
+        for err in errors {
+            eprintln!("warning: {}", err);
+        }
+        solvers
+    })
+}
+
+/// Finds the plugin solver for `variant` of `day` of `year`.
+pub fn find(year: usize, day: usize, variant: &str) -> Option<&'static PluginSolver> {
+    solvers()
+        .iter()
+        .find(|solver| solver.year == year && solver.day == day && solver.variant == variant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_dir, REGISTER_SYMBOL};
+    use crate::ffi::AOC_PLUGIN_ABI_VERSION;
+    use std::fs;
+
+    #[test]
+    fn test_register_symbol() {
+        let expected = format!("aoc_plugin_register_v{}", AOC_PLUGIN_ABI_VERSION);
+        assert_eq!(REGISTER_SYMBOL, expected);
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_dir(&dir.path().join("missing")).0.is_empty());
+
+        let path = dir
+            .path()
+            .join(format!("broken.{}", std::env::consts::DLL_EXTENSION));
+        fs::write(&path, "not a library").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+        let (solvers, errors) = load_dir(dir.path());
+        assert!(solvers.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("broken"));
+    }
+}
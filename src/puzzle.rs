@@ -0,0 +1,276 @@
+use crate::config;
+use crate::error::{Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Path of the Markdown description of the given day.
+pub fn puzzle_path(year: usize, day: usize) -> String {
+    let path = config::get()
+        .puzzles_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.md", day));
+    path.display().to_string()
+}
+
+/// Path of the `k`-th code block of the given day's description, a candidate example input.
+pub fn example_block_path(year: usize, day: usize, k: usize) -> String {
+    let path = config::get()
+        .inputs_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.example-{}.txt", day, k));
+    path.display().to_string()
+}
+
+/// The numbers and paths of the code blocks extracted from the given day's description, in order.
+pub fn example_block_paths(year: usize, day: usize) -> Vec<(usize, PathBuf)> {
+    let prefix = format!("day{:02}.example-", day);
+    let mut blocks = fs::read_dir(config::get().inputs_dir.join(year.to_string()))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let k = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(&prefix)?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+            Some((k, path))
+        })
+        .collect::<Vec<_>>();
+    blocks.sort();
+    blocks
+}
+
+/// A puzzle description converted from the puzzle page.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Description {
+    pub markdown: String,
+    /// The contents of every `<pre><code>` block, in order.
+    pub code_blocks: Vec<String>,
+}
+
+impl Description {
+    /// Writes the Markdown to `puzzles/YYYY/dayNN.md` and each code block to
+    /// `inputs/YYYY/dayNN.example-K.txt`, returning the paths that were written.
+    pub fn save(&self, year: usize, day: usize) -> Result<Vec<String>> {
+        let mut files = vec![(puzzle_path(year, day), &self.markdown)];
+        for (k, block) in self.code_blocks.iter().enumerate() {
+            files.push((example_block_path(year, day, k + 1), block));
+        }
+
+        for (path, contents) in &files {
+            if let Some(dir) = Path::new(path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, contents).map_err(|err| Error::new(format!("{}: {}", path, err)))?;
+        }
+        Ok(files.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+/// Splits HTML into text and tags, skipping comments.
+fn tokenize(mut html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    while !html.is_empty() {
+        if let Some(rest) = html.strip_prefix("<!--") {
+            html = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+        } else if let Some(rest) = html.strip_prefix('<') {
+            let end = rest.find('>').unwrap_or(rest.len());
+            let tag = rest[..end].trim_end_matches('/');
+            html = rest.get(end + 1..).unwrap_or("");
+
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else {
+                let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attributes));
+            }
+        } else {
+            let end = html.find('<').unwrap_or(html.len());
+            tokens.push(Token::Text(&html[..end]));
+            html = &html[end..];
+        }
+    }
+    tokens
+}
+
+/// Returns the value of attribute `name` in a tag's attributes.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = attributes[start..].find('"')?;
+    Some(&attributes[start..start + end])
+}
+
+/// Decodes the character references that show up on puzzle pages.
+pub(crate) fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#')?.parse().ok(),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, c) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Converts the `<article>`s of a puzzle page to Markdown and collects its code blocks.
+pub fn parse_description(html: &str) -> Description {
+    let mut description = Description::default();
+    let out = &mut description.markdown;
+
+    let mut in_article = false;
+    let mut in_code = false;
+    let mut code_block: Option<String> = None;
+    let mut links = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open("article", _) => in_article = true,
+            Token::Close("article") => in_article = false,
+            _ if !in_article => {}
+
+            Token::Open("pre", _) => code_block = Some(String::new()),
+            Token::Close("pre") => {
+                if let Some(block) = code_block.take() {
+                    out.push_str("```\n");
+                    out.push_str(&block);
+                    if !block.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                    description.code_blocks.push(block);
+                }
+            }
+            Token::Text(text) if code_block.is_some() => {
+                code_block
+                    .as_mut()
+                    .unwrap()
+                    .push_str(&decode_entities(text));
+            }
+            _ if code_block.is_some() => {}
+
+            Token::Open("h2", _) => out.push_str("## "),
+            Token::Close("h2" | "p" | "ul") => out.push_str("\n\n"),
+            Token::Open("li", _) => out.push_str("- "),
+            Token::Close("li") => out.push('\n'),
+            Token::Open("code", _) => {
+                in_code = true;
+                out.push('`');
+            }
+            Token::Close("code") => {
+                in_code = false;
+                out.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !in_code => out.push('*'),
+            Token::Open("a", attributes) => {
+                links.push(attribute(attributes, "href").unwrap_or("").to_string());
+                out.push('[');
+            }
+            Token::Close("a") => {
+                out.push_str("](");
+                out.push_str(&links.pop().unwrap_or_default());
+                out.push(')');
+            }
+            // Whitespace between blocks would end up at the start of the next line.
+            Token::Text(text)
+                if text.trim().is_empty() && (out.is_empty() || out.ends_with('\n')) => {}
+            Token::Text(text) => out.push_str(&decode_entities(text).replace('\n', " ")),
+            _ => {}
+        }
+
+        while out.ends_with("\n\n\n") {
+            out.pop();
+        }
+    }
+
+    let trimmed_len = out.trim_end().len();
+    out.truncate(trimmed_len);
+    out.push('\n');
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_description;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_description() {
+        let description = parse_description(include_str!("../tests/fixtures/day01.html"));
+        assert_eq!(
+            description.markdown,
+            indoc! {"
+                ## --- Day 1: Historian Hysteria ---
+
+                The lists are *not* the same, so compare them pair by pair.
+
+                For example:
+
+                ```
+                3   4
+                4   3
+                2   5
+                1   3
+                3   9
+                3   3
+                ```
+
+                Within each pair, figure out *how far apart* the two numbers are; in the example, that adds up to `11`.
+
+                - The smallest numbers are `1` & `3`, a distance of *2*.
+                - The next pair is `2 < 3`, see [the rules](/2024/about).
+
+                What is the total distance between your lists?
+
+                ## --- Part Two ---
+
+                This time, count how often each number occurs:
+
+                ```
+                a > b
+                ```
+
+                What is their *similarity score*?
+            "}
+        );
+        assert_eq!(
+            description.code_blocks,
+            vec!["3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n", "a > b\n"]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::days::PartSolution;
+use crate::runner::{DayOutcome, DayRun, InputSource};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Output format of the `solve` command.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Implemented,
+    NotImplemented,
+    Skipped,
+    Error,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Implemented => "implemented",
+            Status::NotImplemented => "not_implemented",
+            Status::Skipped => "skipped",
+            Status::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub status: Status,
+    #[serde(rename = "type")]
+    pub type_name: Option<&'static str>,
+    pub value: Option<String>,
+    pub elapsed_ns: Option<u128>,
+}
+
+impl PartReport {
+    fn new(status: Status) -> Self {
+        Self {
+            status,
+            type_name: None,
+            value: None,
+            elapsed_ns: None,
+        }
+    }
+
+    fn solved(part: &Option<PartSolution>, time: Option<Duration>) -> Self {
+        let status = match (part, time) {
+            (_, None) => Status::Skipped,
+            (Some(_), _) => Status::Implemented,
+            (None, _) => Status::NotImplemented,
+        };
+
+        Self {
+            status,
+            type_name: part.as_ref().map(PartSolution::type_name),
+            value: part.as_ref().map(PartSolution::to_string),
+            elapsed_ns: time.map(|time| time.as_nanos()),
+        }
+    }
+}
+
+/// Machine-readable summary of a single [`DayRun`].
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub year: usize,
+    pub day: usize,
+    pub status: Status,
+    pub error: Option<String>,
+    pub part_1: PartReport,
+    pub part_2: PartReport,
+    pub parse_ns: Option<u128>,
+    pub elapsed_ns: Option<u128>,
+}
+
+impl DayReport {
+    pub fn new(run: &DayRun, source: &InputSource) -> Self {
+        let error_report = |status: Status, error: Option<String>| Self {
+            year: run.year,
+            day: run.day,
+            status,
+            error,
+            part_1: PartReport::new(status),
+            part_2: PartReport::new(status),
+            parse_ns: None,
+            elapsed_ns: None,
+        };
+
+        match &run.outcome {
+            DayOutcome::NotImplemented => error_report(Status::NotImplemented, None),
+            DayOutcome::NoInput => error_report(
+                Status::Error,
+                Some(format!("no input: {}", source.describe(run.year, run.day))),
+            ),
+            DayOutcome::Error(_) | DayOutcome::Panicked(_) | DayOutcome::TimedOut(_) => {
+                error_report(Status::Error, run.outcome.failure())
+            }
+            DayOutcome::Solved(solution) => Self {
+                year: run.year,
+                day: run.day,
+                status: Status::Implemented,
+                error: None,
+                part_1: PartReport::solved(&solution.part_1, solution.part_1_time),
+                part_2: PartReport::solved(&solution.part_2, solution.part_2_time),
+                parse_ns: Some(solution.parse_time.as_nanos()),
+                elapsed_ns: Some(solution.total_time().as_nanos()),
+            },
+        }
+    }
+}
+
+/// Writes the reports as a JSON array, even for a single day.
+pub fn write_json(writer: impl Write, reports: &[DayReport]) -> io::Result<()> {
+    serde_json::to_writer_pretty(writer, reports).map_err(io::Error::from)
+}
+
+/// Writes the reports as CSV with a header row and one row per day.
+pub fn write_csv(mut writer: impl Write, reports: &[DayReport]) -> io::Result<()> {
+    writeln!(
+        writer,
+        "year,day,status,error,parse_ns,part_1_status,part_1_type,part_1_value,part_1_elapsed_ns,\
+        part_2_status,part_2_type,part_2_value,part_2_elapsed_ns,elapsed_ns"
+    )?;
+
+    for report in reports {
+        let mut fields = vec![
+            report.year.to_string(),
+            report.day.to_string(),
+            report.status.as_str().to_string(),
+            report.error.clone().unwrap_or_default(),
+            format_ns(report.parse_ns),
+        ];
+        for part in [&report.part_1, &report.part_2] {
+            fields.push(part.status.as_str().to_string());
+            fields.push(part.type_name.unwrap_or_default().to_string());
+            fields.push(part.value.clone().unwrap_or_default());
+            fields.push(format_ns(part.elapsed_ns));
+        }
+        fields.push(format_ns(report.elapsed_ns));
+
+        let fields = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>();
+        writeln!(writer, "{}", fields.join(","))?;
+    }
+
+    Ok(())
+}
+
+fn format_ns(ns: Option<u128>) -> String {
+    ns.map(|ns| ns.to_string()).unwrap_or_default()
+}
+
+/// Quotes a CSV field if needed. Answers like `4,6,3` contain commas.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_csv, write_json, DayReport};
+    use crate::days::TimedSolution;
+    use crate::runner::{DayOutcome, DayRun, InputSource};
+    use std::time::Duration;
+
+    #[test]
+    fn test_write_reports() {
+        let runs = [
+            DayRun {
+                year: 2024,
+                day: 1,
+                outcome: DayOutcome::Solved(TimedSolution {
+                    part_1: Some("4,6,3".to_string().into()),
+                    part_2: None,
+                    parse_time: Duration::from_nanos(500),
+                    part_1_time: Some(Duration::from_nanos(1000)),
+                    part_2_time: None,
+                }),
+            },
+            DayRun {
+                year: 2024,
+                day: 2,
+                outcome: DayOutcome::NoInput,
+            },
+        ];
+        let reports = runs
+            .iter()
+            .map(|run| DayReport::new(run, &InputSource::Puzzle))
+            .collect::<Vec<_>>();
+
+        let mut output = vec![];
+        write_csv(&mut output, &reports).unwrap();
+        assert_eq!(
+            String::from_utf8(output)
+                .unwrap()
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>(),
+            [
+                "2024,1,implemented,,500,implemented,String,\"4,6,3\",1000,skipped,,,,1500",
+                "2024,2,error,no input: inputs/2024/day02.txt,,error,,,,error,,,,",
+            ]
+        );
+
+        let mut output = vec![];
+        write_json(&mut output, &reports[..1]).unwrap();
+        let json = serde_json::from_slice::<serde_json::Value>(&output).unwrap();
+        assert_eq!(json.as_array().map(Vec::len), Some(1));
+        assert_eq!(json[0]["part_1"]["value"], "4,6,3");
+    }
+}
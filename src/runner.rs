@@ -0,0 +1,457 @@
+use crate::config;
+use crate::days::{DaySolver, PartSolution, Parts, TimedSolution};
+use crate::error::Error;
+use crate::params::ParamValue;
+use crate::plugin::{self, PluginSolver};
+use crate::util::table::Table;
+use crate::years;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// Path of the puzzle input for the given day.
+pub fn input_path(year: usize, day: usize) -> String {
+    let path = config::get()
+        .inputs_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day));
+    path.display().to_string()
+}
+
+/// Path of the example input for the given day.
+pub fn example_path(year: usize, day: usize) -> String {
+    let path = config::get()
+        .inputs_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.example.txt", day));
+    path.display().to_string()
+}
+
+/// Where a solver reads its input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// The puzzle input at `inputs/YYYY/dayNN.txt`.
+    Puzzle,
+    /// The example input at `inputs/YYYY/dayNN.example.txt`.
+    Example,
+    /// An arbitrary file.
+    Path(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Whether this source can be used for more than one day.
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, InputSource::Puzzle | InputSource::Example)
+    }
+
+    pub fn describe(&self, year: usize, day: usize) -> String {
+        match self {
+            InputSource::Puzzle => input_path(year, day),
+            InputSource::Example => example_path(year, day),
+            InputSource::Path(path) => path.display().to_string(),
+            InputSource::Stdin => "<stdin>".to_string(),
+        }
+    }
+
+    pub fn read(&self, year: usize, day: usize) -> io::Result<String> {
+        match self {
+            InputSource::Puzzle => fs::read_to_string(input_path(year, day)),
+            InputSource::Example => fs::read_to_string(example_path(year, day)),
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// An inclusive range of days selected on the command line, e.g. `5`, `3..9` or `3..=9`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DayRange {
+    pub first: usize,
+    pub last: usize,
+}
+
+impl DayRange {
+    /// Every day of an Advent of Code year.
+    pub const ALL: Self = Self { first: 1, last: 25 };
+
+    /// The days in this range, whether or not they have a solver.
+    pub fn iter(self) -> RangeInclusive<usize> {
+        self.first..=self.last
+    }
+
+    /// Returns the days of the given year within this range that have a solver, or the given
+    /// variant of one.
+    pub fn days(self, year: usize, variant: Option<&str>) -> Vec<usize> {
+        self.iter()
+            .filter(|&day| Solver::find(year, day, variant).is_some())
+            .collect()
+    }
+
+    pub fn is_single(self) -> bool {
+        self.first == self.last
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| {
+            s.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid day: '{}'", s))
+        };
+
+        let (first, last) = if let Some((first, last)) = s.split_once("..=") {
+            (parse_day(first)?, parse_day(last)?)
+        } else if let Some((first, last)) = s.split_once("..") {
+            let last = parse_day(last)?;
+            if last == 0 {
+                return Err(format!("empty day range: '{}'", s));
+            }
+            (parse_day(first)?, last - 1)
+        } else {
+            let day = parse_day(s)?;
+            (day, day)
+        };
+
+        if first > last {
+            return Err(format!("empty day range: '{}'", s));
+        }
+
+        Ok(Self { first, last })
+    }
+}
+
+pub enum DayOutcome {
+    NotImplemented,
+    NoInput,
+    Solved(TimedSolution),
+    /// The solver rejected the input.
+    Error(Error),
+    /// The solver panicked, with the panic message.
+    Panicked(String),
+    /// The solver did not finish within the configured timeout.
+    TimedOut(Duration),
+}
+
+impl DayOutcome {
+    /// Why the solver failed, if it rejected the input, panicked or timed out.
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            DayOutcome::Error(err) => Some(err.detail()),
+            DayOutcome::Panicked(message) => Some(format!("panicked: {}", message)),
+            DayOutcome::TimedOut(timeout) => Some(format!("timed out after {:?}", timeout)),
+            _ => None,
+        }
+    }
+}
+
+pub struct DayRun {
+    pub year: usize,
+    pub day: usize,
+    pub outcome: DayOutcome,
+}
+
+impl DayRun {
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self.outcome {
+            DayOutcome::Solved(ref solution) => Some(solution.total_time()),
+            _ => None,
+        }
+    }
+}
+
+/// A solver for a day: a built-in one (the solution or one of its variants), or a variant loaded
+/// from a plugin.
+#[derive(Copy, Clone)]
+pub enum Solver {
+    BuiltIn(DaySolver),
+    Plugin(&'static PluginSolver),
+}
+
+impl Solver {
+    /// Finds the built-in solver for `day` of `year`, or the given variant of it. Built-in variants
+    /// take precedence over plugins with the same name.
+    pub fn find(year: usize, day: usize, variant: Option<&str>) -> Option<Self> {
+        let Some(variant) = variant else {
+            return years::solver(year, day).ok().map(Solver::BuiltIn);
+        };
+        match years::variants(year, day)
+            .iter()
+            .find(|v| v.name == variant)
+        {
+            Some(v) => Some(Solver::BuiltIn(v.solver)),
+            None => plugin::find(year, day, variant).map(Solver::Plugin),
+        }
+    }
+
+    /// The names of the variants of `day` of `year`: the built-in ones, then those from plugins.
+    pub fn variants(year: usize, day: usize) -> Vec<String> {
+        let mut names = years::variants(year, day)
+            .iter()
+            .map(|v| v.name.to_string())
+            .collect::<Vec<_>>();
+        for solver in plugin::solvers() {
+            if solver.year == year && solver.day == day && !names.contains(&solver.variant) {
+                names.push(solver.variant.clone());
+            }
+        }
+        names
+    }
+
+    pub fn solve(
+        self,
+        input: &str,
+        params: &[ParamValue],
+        parts: Parts,
+    ) -> Result<TimedSolution, Error> {
+        match self {
+            Solver::BuiltIn(solve) => solve(input, params, parts),
+            Solver::Plugin(_) if !params.is_empty() => {
+                Err(Error::new("plugin solvers don't take parameters"))
+            }
+            Solver::Plugin(solver) => solver.solve(input, parts),
+        }
+    }
+}
+
+/// Runs the solver for `day` of `year`, or the given variant of it, on the input from `source`
+/// with the given puzzle parameters.
+pub fn run_day(
+    year: usize,
+    day: usize,
+    variant: Option<&str>,
+    params: &[ParamValue],
+    source: &InputSource,
+    parts: Parts,
+) -> DayRun {
+    let outcome = match source.read(year, day) {
+        Err(_) if Solver::find(year, day, variant).is_some() => DayOutcome::NoInput,
+        Err(_) => DayOutcome::NotImplemented,
+        Ok(input) => solve_input(year, day, variant, params, &input, parts),
+    };
+
+    DayRun { year, day, outcome }
+}
+
+/// Runs the given days on `jobs` threads at a time, returning the runs in the order of `days`.
+pub fn run_days(
+    year: usize,
+    days: &[usize],
+    variant: Option<&str>,
+    params: &[ParamValue],
+    source: &InputSource,
+    parts: Parts,
+    jobs: usize,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let run = run_day(year, day, variant, params, source, parts);
+                    runs.lock().unwrap().push(run);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| days.iter().position(|&day| day == run.day));
+    runs
+}
+
+/// Runs the solver for `day` of `year`, or the given variant of it, on the given input with the
+/// given puzzle parameters.
+///
+/// The solver runs on its own thread with the configured stack size, so that a panic or deep
+/// recursion only fails this day. A solver that exceeds the configured timeout is left running in
+/// the background until the process exits.
+pub fn solve_input(
+    year: usize,
+    day: usize,
+    variant: Option<&str>,
+    params: &[ParamValue],
+    input: &str,
+    parts: Parts,
+) -> DayOutcome {
+    let Some(solver) = Solver::find(year, day, variant) else {
+        return DayOutcome::NotImplemented;
+    };
+
+    let config = config::get();
+    match run_isolated(
+        solver,
+        input,
+        params,
+        parts,
+        config.stack_size,
+        config.timeout,
+    ) {
+        DayOutcome::Error(err) => DayOutcome::Error(err.with_day(day)),
+        outcome => outcome,
+    }
+}
+
+fn run_isolated(
+    solver: Solver,
+    input: &str,
+    params: &[ParamValue],
+    parts: Parts,
+    stack_size: usize,
+    timeout: Option<Duration>,
+) -> DayOutcome {
+    let input = input.to_string();
+    let params = params.to_vec();
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .stack_size(stack_size)
+        .spawn(move || {
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| solver.solve(&input, &params, parts)));
+            // The receiver is gone if the run timed out.
+            let _ = sender.send(result);
+        });
+    if let Err(err) = spawned {
+        return DayOutcome::Error(Error::from(err));
+    }
+
+    let result = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match result {
+        Ok(Ok(Ok(solution))) => DayOutcome::Solved(solution),
+        Ok(Ok(Err(err))) => DayOutcome::Error(err),
+        Ok(Err(payload)) => DayOutcome::Panicked(panic_message(payload.as_ref())),
+        Err(RecvTimeoutError::Timeout) => DayOutcome::TimedOut(timeout.unwrap()),
+        Err(RecvTimeoutError::Disconnected) => DayOutcome::Panicked("no result".to_string()),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Formats a part's solution for humans. A part that was not run has no time.
+pub fn format_part(part: &Option<PartSolution>, time: Option<Duration>) -> String {
+    match (part, time) {
+        (_, None) => "skipped".to_string(),
+        (Some(part), _) => part.to_string(),
+        (None, _) => "not yet implemented".to_string(),
+    }
+}
+
+/// Formats the runs as a table with one row per day and a final row with the total runtime.
+pub struct SummaryTable<'a>(pub &'a [DayRun]);
+
+impl Display for SummaryTable<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut table = Table::new(["Day", "Part 1", "Part 2", "Elapsed"]);
+        table.right_aligned[3] = true;
+        for run in self.0 {
+            let day = format!("{:02}", run.day);
+            table.rows.push(match &run.outcome {
+                DayOutcome::NotImplemented => [
+                    day,
+                    "not yet implemented".to_string(),
+                    String::new(),
+                    String::new(),
+                ],
+                DayOutcome::NoInput => [day, "no input".to_string(), String::new(), String::new()],
+                DayOutcome::Error(err) => [
+                    day,
+                    format!("error: {}", err.detail()),
+                    String::new(),
+                    String::new(),
+                ],
+                outcome @ (DayOutcome::Panicked(_) | DayOutcome::TimedOut(_)) => [
+                    day,
+                    outcome.failure().unwrap_or_default(),
+                    String::new(),
+                    String::new(),
+                ],
+                DayOutcome::Solved(solution) => [
+                    day,
+                    format_part(&solution.part_1, solution.part_1_time),
+                    format_part(&solution.part_2, solution.part_2_time),
+                    format!("{:?}", solution.total_time()),
+                ],
+            });
+        }
+
+        let total: Duration = self.0.iter().filter_map(DayRun::elapsed).sum();
+        table.separators.push(table.rows.len());
+        table.rows.push([
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            format!("{:?}", total),
+        ]);
+        table.write(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_days, run_isolated, DayOutcome, DayRange, InputSource, Solver};
+    use crate::days::Parts;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!("5".parse(), Ok(DayRange { first: 5, last: 5 }));
+        assert_eq!("3..=9".parse(), Ok(DayRange { first: 3, last: 9 }));
+        assert_eq!("3..9".parse(), Ok(DayRange { first: 3, last: 8 }));
+        assert!("9..=3".parse::<DayRange>().is_err());
+        assert!("3..3".parse::<DayRange>().is_err());
+        assert!("x".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn test_run_days() {
+        let days = [3, 1, 2, 5, 4];
+        let runs = run_days(1999, &days, None, &[], &InputSource::Puzzle, Parts::Both, 3);
+        assert_eq!(runs.iter().map(|run| run.day).collect::<Vec<_>>(), days);
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let solver = Solver::BuiltIn(|_, _, _| panic!("oops"));
+        let outcome = run_isolated(solver, "", &[], Parts::Both, 1 << 20, None);
+        assert!(matches!(outcome, DayOutcome::Panicked(message) if message == "oops"));
+
+        let timeout = Duration::from_millis(10);
+        let solver = Solver::BuiltIn(|_, _, _| {
+            thread::sleep(Duration::from_secs(1));
+            unreachable!()
+        });
+        let outcome = run_isolated(solver, "", &[], Parts::Both, 1 << 20, Some(timeout));
+        assert_eq!(outcome.failure().as_deref(), Some("timed out after 10ms"));
+    }
+}
@@ -0,0 +1,49 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("years/dayN.rs");
+
+pub fn module_name(day: usize) -> String {
+    format!("day{:02}", day)
+}
+
+pub fn module_path(year: usize, day: usize) -> String {
+    format!("src/years/y{}/{}.rs", year, module_name(day))
+}
+
+/// Renders the `dayN.rs` template for the given day.
+pub fn render_template(day: usize) -> String {
+    TEMPLATE.replace(
+        "pub const DAY: usize = 0;",
+        &format!("pub const DAY: usize = {};", day),
+    )
+}
+
+/// Creates `src/years/yYYYY/dayNN.rs` from the template, along with the year's directory if it is
+/// the first day of that year. Refuses to overwrite an existing module. The build script picks up
+/// the new module automatically.
+pub fn new_day(year: usize, day: usize) -> io::Result<()> {
+    let path = module_path(year, day);
+    if Path::new(&path).exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", path),
+        ));
+    }
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, render_template(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_template;
+
+    #[test]
+    fn test_render_template() {
+        assert!(render_template(17).contains("\npub const DAY: usize = 17;\n"));
+    }
+}
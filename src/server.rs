@@ -0,0 +1,146 @@
+//! A local HTTP service that exposes the solvers, for tools that want answers without running the
+//! binary.
+//!
+//! ```text
+//! GET  /days        the implemented days, as `{"year": 2024, "days": [1, 2, ...]}`
+//! POST /solve/DAY   solves the input in the request body, replying with a `DayReport` as JSON
+//! ```
+
+use crate::days::Parts;
+use crate::report::DayReport;
+use crate::runner::{self, DayOutcome, DayRun, InputSource};
+use crate::years;
+use serde_json::json;
+use std::io;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+pub struct SolveServer {
+    server: Arc<Server>,
+    url: String,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl SolveServer {
+    /// Starts serving the solvers of `year` on `127.0.0.1:port`, or on a free port if `port` is 0.
+    pub fn start(year: usize, port: u16) -> io::Result<Self> {
+        let server = Server::http(("127.0.0.1", port)).map_err(io::Error::other)?;
+        let server = Arc::new(server);
+        let url = format!("http://{}", server.server_addr());
+
+        // A fixed pool of workers take turns receiving requests, so a slow solve only holds up
+        // its own worker. Solves run isolated like on the command line, see
+        // `runner::solve_input`, so the configured timeout bounds how long that is.
+        let workers = thread::available_parallelism().map_or(4, NonZeroUsize::get);
+        let workers = (0..workers)
+            .map(|_| {
+                let server = server.clone();
+                thread::spawn(move || {
+                    for request in server.incoming_requests() {
+                        handle(year, request);
+                    }
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            server,
+            url,
+            workers,
+        })
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:4321`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Serves requests until the process is stopped.
+    pub fn wait(mut self) {
+        for worker in self.workers.drain(..) {
+            worker.join().unwrap();
+        }
+    }
+}
+
+impl Drop for SolveServer {
+    fn drop(&mut self) {
+        // Each call unblocks a single worker.
+        for _ in &self.workers {
+            self.server.unblock();
+        }
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn handle(year: usize, mut request: Request) {
+    let mut body = String::new();
+    let (status, json) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => reply(year, request.method(), request.url(), &body),
+        Err(_) => error(400, "the input must be UTF-8"),
+    };
+
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    let _ = request.respond(response);
+}
+
+fn error(status: u16, message: &str) -> (u16, serde_json::Value) {
+    (status, json!({ "error": message }))
+}
+
+fn reply(year: usize, method: &Method, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match (method, &segments[..]) {
+        (Method::Get, ["days"]) => (200, json!({ "year": year, "days": years::days(year) })),
+        (Method::Post, ["solve", day]) => {
+            let Ok(day) = day.parse::<usize>() else {
+                return error(400, &format!("invalid day: '{}'", day));
+            };
+            let run = DayRun {
+                year,
+                day,
+                outcome: runner::solve_input(year, day, None, &[], body, Parts::Both),
+            };
+            let status = match run.outcome {
+                DayOutcome::Solved(_) => 200,
+                DayOutcome::NotImplemented => 404,
+                _ => 422,
+            };
+            let report = DayReport::new(&run, &InputSource::Stdin);
+            (status, serde_json::to_value(report).unwrap())
+        }
+        (_, ["days"] | ["solve", _]) => error(405, "method not allowed"),
+        _ => error(404, "not found"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reply;
+    use tiny_http::Method;
+
+    #[test]
+    fn test_reply() {
+        let (status, json) = reply(2024, &Method::Get, "/days", "");
+        assert_eq!(status, 200);
+        assert_eq!(json["days"][0], 1);
+
+        let (status, json) = reply(2024, &Method::Post, "/solve/1", "1   3\n");
+        assert_eq!(status, 200);
+        assert_eq!(json["part_1"]["value"], "2");
+
+        let (status, json) = reply(2024, &Method::Post, "/solve/1", "3 4 5\n");
+        assert_eq!(status, 422);
+        assert_eq!(json["status"], "error");
+
+        assert_eq!(reply(2024, &Method::Post, "/solve/26", "").0, 404);
+        assert_eq!(reply(2024, &Method::Get, "/solve/1", "").0, 405);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::config;
+use crate::error::{Error, Result};
+use homedir::my_home;
+use std::env;
+use std::fmt::{Debug, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that holds the session cookie, taking precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Path of the file that holds the session cookie, `~/.adventofcode.session` unless configured
+/// otherwise.
+pub fn session_path() -> Result<PathBuf> {
+    let home = || {
+        my_home()
+            .ok()
+            .flatten()
+            .ok_or_else(|| Error::new("could not determine the home directory"))
+    };
+    match &config::get().session_file {
+        Some(path) => match path.strip_prefix("~") {
+            Ok(path) => Ok(home()?.join(path)),
+            Err(_) => Ok(path.clone()),
+        },
+        None => Ok(home()?.join(".adventofcode.session")),
+    }
+}
+
+/// A session cookie. Only ever shown redacted, so it can't end up in logs or error messages.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Session(String);
+
+impl Session {
+    pub fn new(token: &str) -> Result<Self> {
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(Error::new("invalid session: empty"));
+        }
+        if !token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Error::new(
+                "invalid session: expected only letters, digits, '-' and '_'",
+            ));
+        }
+        Ok(Self(token.to_string()))
+    }
+
+    /// The token itself, for sending it to the server.
+    pub fn token(&self) -> &str {
+        &self.0
+    }
+
+    /// The first few characters of the token, enough to tell sessions apart.
+    pub fn redacted(&self) -> String {
+        let prefix = self.0.chars().take(4).collect::<String>();
+        format!("{}… (redacted)", prefix)
+    }
+}
+
+impl Display for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.redacted())
+    }
+}
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session({})", self.redacted())
+    }
+}
+
+/// Where a session was read from.
+pub enum Source {
+    Env,
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Env => f.write_str(SESSION_ENV),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads the session from `AOC_SESSION`, or from the session file if that is not set.
+pub fn load() -> Result<Session> {
+    load_with_source().map(|(session, _)| session)
+}
+
+pub fn load_with_source() -> Result<(Session, Source)> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            let session = Session::new(&token)
+                .map_err(|err| Error::new(format!("{}: {}", SESSION_ENV, err.message)))?;
+            return Ok((session, Source::Env));
+        }
+    }
+
+    let path = session_path()?;
+    let token = fs::read_to_string(&path).map_err(|err| {
+        Error::new(format!(
+            "no session: set {}, or store one with `session set` ({}: {})",
+            SESSION_ENV,
+            path.display(),
+            err
+        ))
+    })?;
+    warn_if_readable_by_others(&path);
+    let session = Session::new(&token)
+        .map_err(|err| Error::new(format!("{}: {}", path.display(), err.message)))?;
+    Ok((session, Source::File(path)))
+}
+
+#[cfg(unix)]
+fn warn_if_readable_by_others(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Ok(metadata) = fs::metadata(path) {
+        if metadata.permissions().mode() & 0o077 != 0 {
+            eprintln!(
+                "warning: {} is accessible by other users, restrict it with `chmod 600`",
+                path.display()
+            );
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn warn_if_readable_by_others(_path: &Path) {}
+
+/// Writes the session to the session file, readable only by the current user.
+pub fn save(session: &Session) -> Result<PathBuf> {
+    let path = session_path()?;
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode only applies to new files, so tighten an existing one before writing to it.
+        if path.exists() {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    let mut file = options
+        .open(&path)
+        .map_err(|err| Error::new(format!("{}: {}", path.display(), err)))?;
+    writeln!(file, "{}", session.token())?;
+    Ok(path)
+}
+
+/// Removes the session file, returning whether there was one.
+pub fn clear() -> Result<bool> {
+    let path = session_path()?;
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(Error::new(format!("{}: {}", path.display(), err))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+
+    #[test]
+    fn test_session() {
+        let session = Session::new("53616c7465645f5f0123456789abcdef\n").unwrap();
+        assert_eq!(session.token(), "53616c7465645f5f0123456789abcdef");
+        assert_eq!(session.to_string(), "5361… (redacted)");
+        assert_eq!(format!("{:?}", session), "Session(5361… (redacted))");
+
+        assert!(Session::new("").is_err());
+        let err = Session::new("abc; other=1").unwrap_err();
+        assert!(!err.to_string().contains("abc"));
+    }
+}
@@ -0,0 +1,288 @@
+use crate::config;
+use crate::error::{Error, Result};
+use crate::puzzle;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Path of the log of submitted guesses for the given day.
+pub fn guesses_path(year: usize, day: usize) -> String {
+    let path = config::get()
+        .answers_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.guesses.txt", day));
+    path.display().to_string()
+}
+
+/// The server's reply to a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently; the guess was not checked.
+    RateLimited(Option<Duration>),
+    /// The part is already solved or not unlocked yet; the guess was not checked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML the server answers a submission with.
+    pub fn from_response(html: &str) -> Result<Self> {
+        let text = puzzle::parse_description(html).markdown;
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Ok(Verdict::TooHigh)
+            } else if text.contains("too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Wrong)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Ok(Verdict::RateLimited(parse_wait(&text)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err(Error::new(format!("unexpected response: {}", text.trim())))
+        }
+    }
+
+    /// Whether the server actually checked the guess.
+    pub fn is_checked(&self) -> bool {
+        !matches!(self, Verdict::RateLimited(_) | Verdict::WrongLevel)
+    }
+}
+
+/// Parses the wait time from e.g. "You have 1m 5s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::TooHigh => f.write_str("too high"),
+            Verdict::TooLow => f.write_str("too low"),
+            Verdict::RateLimited(None) => f.write_str("rate limited"),
+            // Whole seconds, which is what the server reports and `from_str` reads back.
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::WrongLevel => f.write_str("wrong level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "rate limited" => Ok(Verdict::RateLimited(None)),
+            "wrong level" => Ok(Verdict::WrongLevel),
+            s => match s.strip_prefix("rate limited, wait ") {
+                Some(wait) => Ok(Verdict::RateLimited(Some(Duration::from_secs(
+                    wait.trim_end_matches('s')
+                        .parse()
+                        .map_err(|_| format!("invalid wait: '{}'", wait))?,
+                )))),
+                None => Err(format!("unknown verdict: '{}'", s)),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for one day, in order.
+///
+/// Stored with one guess per line:
+///
+/// ```text
+/// part 1 too high: 1234
+/// part 1 correct: 1000
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    pub fn load(year: usize, day: usize) -> io::Result<Self> {
+        match fs::read_to_string(guesses_path(year, day)) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, year: usize, day: usize) -> io::Result<()> {
+        let path = guesses_path(year, day);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Refuses an answer that is already known to be wrong, either because it was guessed before
+    /// or because it is outside the bounds set by earlier "too high" and "too low" replies.
+    pub fn check(&self, part: u8, answer: &str) -> std::result::Result<(), String> {
+        let value = answer.parse::<i128>().ok();
+        for guess in self.guesses.iter().filter(|guess| guess.part == part) {
+            let guessed = guess.answer.parse::<i128>().ok();
+            match guess.verdict {
+                Verdict::Correct => {
+                    return Err(format!(
+                        "part {} was already solved: {}",
+                        part, guess.answer
+                    ))
+                }
+                _ if !guess.verdict.is_checked() => {}
+                _ if guess.answer == answer => {
+                    return Err(format!("{} was already guessed: {}", answer, guess.verdict))
+                }
+                Verdict::TooHigh
+                    if value
+                        .zip(guessed)
+                        .is_some_and(|(value, guessed)| value >= guessed) =>
+                {
+                    return Err(format!("{} is too high: {} was", answer, guess.answer))
+                }
+                Verdict::TooLow
+                    if value
+                        .zip(guessed)
+                        .is_some_and(|(value, guessed)| value <= guessed) =>
+                {
+                    return Err(format!("{} is too low: {} was", answer, guess.answer))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.guesses.push(Guess {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+impl FromStr for GuessLog {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut guesses = vec![];
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid_line = || format!("invalid line: '{}'", line);
+            let (key, answer) = line.split_once(':').ok_or_else(invalid_line)?;
+            let (part, verdict) = key
+                .strip_prefix("part ")
+                .and_then(|key| key.split_once(' '))
+                .ok_or_else(invalid_line)?;
+            guesses.push(Guess {
+                part: part.parse().map_err(|_| invalid_line())?,
+                answer: answer.trim().to_string(),
+                verdict: verdict.parse()?,
+            });
+        }
+        Ok(Self { guesses })
+    }
+}
+
+impl Display for GuessLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for guess in &self.guesses {
+            writeln!(f, "part {} {}: {}", guess.part, guess.verdict, guess.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuessLog, Verdict};
+    use std::time::Duration;
+
+    #[test]
+    fn test_guess_log() {
+        let response = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Verdict::from_response(&response(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Ok(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response(&response(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Ok(Verdict::RateLimited(Some(Duration::from_secs(65))))
+        );
+
+        let mut log = GuessLog::default();
+        log.record(1, "500", Verdict::TooHigh);
+        log.record(1, "100", Verdict::TooLow);
+        log.record(1, "250", Verdict::Wrong);
+        log.record(
+            1,
+            "300",
+            Verdict::RateLimited(Some(Duration::from_secs(30))),
+        );
+        log.record(2, "7", Verdict::Correct);
+        assert_eq!(log.to_string().parse(), Ok(log.clone()));
+
+        assert!(log.check(1, "300").is_ok());
+        assert!(log.check(1, "250").is_err());
+        assert!(log.check(1, "500").is_err());
+        assert!(log.check(1, "600").is_err());
+        assert!(log.check(1, "100").is_err());
+        assert!(log.check(1, "50").is_err());
+        assert!(log.check(2, "8").is_err());
+
+        let verdict = Verdict::RateLimited(Some(Duration::ZERO));
+        assert_eq!(verdict.to_string(), "rate limited, wait 0s");
+        assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        let verdict = Verdict::RateLimited(Some(Duration::from_millis(1500)));
+        assert_eq!(
+            verdict.to_string().parse(),
+            Ok(Verdict::RateLimited(Some(Duration::from_secs(1))))
+        );
+    }
+}
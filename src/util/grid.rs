@@ -1,3 +1,4 @@
+use crate::error::{Error, Result};
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
@@ -105,16 +106,58 @@ pub trait RefGridMut<'a>: RefGrid<'a> {
 
 /// Wraps an LF-separated multiline ASCII string with equal line widths such that it can be
 /// addressed like a 2-dimensional grid of bytes.
+#[derive(Copy, Clone)]
 pub struct StrGrid<'a> {
     line_width: usize,
     data: &'a [u8],
 }
 
 impl<'a> StrGrid<'a> {
-    pub fn new(data: &'a [u8]) -> Self {
-        let line_width = data.iter().position(|&c| c == b'\n').unwrap() + 1;
-        assert_eq!(data.len() % line_width, 0);
-        Self { line_width, data }
+    /// Wraps `input`, checking that it is a non-empty grid of LF-terminated lines of equal width.
+    pub fn new(input: &'a str) -> Result<Self> {
+        if let Some(offset) = input.find('\r') {
+            return Err(Error::at_offset(
+                input,
+                offset,
+                "unexpected carriage return (CRLF line endings are not supported)",
+            ));
+        }
+
+        let width = input
+            .find('\n')
+            .filter(|&width| width > 0)
+            .ok_or_else(|| Error::at(1, 1, "expected a grid of one or more lines"))?;
+
+        for (y, line) in input.split_inclusive('\n').enumerate() {
+            match line.strip_suffix('\n') {
+                Some(line) if line.len() == width => {}
+                None if line.len() == width => {
+                    return Err(Error::at(
+                        y + 1,
+                        width + 1,
+                        "missing newline at end of input",
+                    ))
+                }
+                _ => {
+                    let line_len = line.trim_end_matches('\n').len();
+                    return Err(Error::at(
+                        y + 1,
+                        line_len.min(width) + 1,
+                        format!("line has width {}, expected {}", line_len, width),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            line_width: width + 1,
+            data: input.as_bytes(),
+        })
+    }
+
+    /// The underlying bytes, including the newlines.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
     }
 
     fn coord_to_data_index(&self, (x, y): Coord) -> Option<usize> {
@@ -153,6 +196,7 @@ impl<'a> Index<Coord> for StrGrid<'a> {
     }
 }
 
+#[derive(Clone)]
 pub struct VecGrid<T> {
     data: Vec<T>,
     width: usize,
@@ -0,0 +1,56 @@
+use std::fmt::{Formatter, Result};
+
+/// A plain-text table with a header row, `|`-separated columns padded to equal width and
+/// horizontal separators before the given rows.
+pub struct Table<const N: usize> {
+    pub header: [String; N],
+    pub rows: Vec<[String; N]>,
+    /// Whether each column is aligned to the right.
+    pub right_aligned: [bool; N],
+    /// Indices of rows that get a separator line above them.
+    pub separators: Vec<usize>,
+}
+
+impl<const N: usize> Table<N> {
+    pub fn new(header: [&str; N]) -> Self {
+        Self {
+            header: header.map(str::to_string),
+            rows: vec![],
+            right_aligned: [false; N],
+            separators: vec![],
+        }
+    }
+
+    pub fn write(&self, f: &mut Formatter<'_>) -> Result {
+        let mut widths = [0; N];
+        for row in [&self.header].into_iter().chain(&self.rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let separator = widths.map(|width| "-".repeat(width)).join("-+-");
+        let write_row = |f: &mut Formatter<'_>, row: &[String; N]| {
+            let cells = row
+                .iter()
+                .zip(widths)
+                .zip(self.right_aligned)
+                .map(|((cell, width), right_aligned)| match right_aligned {
+                    true => format!("{:>width$}", cell, width = width),
+                    false => format!("{:<width$}", cell, width = width),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join(" | ").trim_end())
+        };
+
+        write_row(f, &self.header)?;
+        writeln!(f, "{}", separator)?;
+        for (i, row) in self.rows.iter().enumerate() {
+            if self.separators.contains(&i) {
+                writeln!(f, "{}", separator)?;
+            }
+            write_row(f, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use crate::error::{Error, Result};
+use crate::runner::{DayOutcome, DayRun};
+use crate::util::table::Table;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Polls a set of files for changes by their modification time. A file that is created or removed
+/// counts as changed.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Returns the files that changed since the previous poll.
+    pub fn poll(&mut self) -> Vec<&Path> {
+        let mut changed = vec![];
+        for (path, last_modified) in &mut self.files {
+            let modified = modified(path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(path.as_path());
+            }
+        }
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Reads expected answers, one `part N: answer` line per part.
+pub fn read_expected(path: &Path) -> Result<[Option<String>; 2]> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| Error::new(format!("{}: {}", path.display(), err)))?;
+
+    let mut expected = [None, None];
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let part = match line.split_once(':') {
+            Some((key, value)) if key.trim() == "part 1" => (0, value),
+            Some((key, value)) if key.trim() == "part 2" => (1, value),
+            _ => {
+                return Err(Error::new(format!(
+                    "{}: expected 'part 1: ...' or 'part 2: ...', got '{}'",
+                    path.display(),
+                    line
+                )))
+            }
+        };
+        expected[part.0] = Some(part.1.trim().to_string());
+    }
+    Ok(expected)
+}
+
+/// The answers and timing of a run, kept to compare the next run against.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Snapshot {
+    pub parts: [Option<String>; 2],
+    pub elapsed: Option<Duration>,
+    /// Why the day could not be solved.
+    pub error: Option<String>,
+}
+
+impl From<&DayRun> for Snapshot {
+    fn from(run: &DayRun) -> Self {
+        let error = |error: String| Self {
+            error: Some(error),
+            ..Self::default()
+        };
+        match &run.outcome {
+            DayOutcome::NotImplemented => error("not yet implemented".to_string()),
+            DayOutcome::NoInput => error("no input".to_string()),
+            outcome
+            @ (DayOutcome::Error(_) | DayOutcome::Panicked(_) | DayOutcome::TimedOut(_)) => {
+                error(outcome.failure().unwrap_or_default())
+            }
+            DayOutcome::Solved(solution) => Self {
+                parts: [
+                    solution.part_1.as_ref().map(ToString::to_string),
+                    solution.part_2.as_ref().map(ToString::to_string),
+                ],
+                elapsed: Some(solution.total_time()),
+                error: None,
+            },
+        }
+    }
+}
+
+/// One input's results in a [`WatchTable`].
+pub struct WatchRow<'a> {
+    pub label: &'a str,
+    pub current: &'a Snapshot,
+    pub previous: Option<&'a Snapshot>,
+    pub expected: Option<&'a [Option<String>; 2]>,
+}
+
+/// Formats the results of a run with what changed since the previous run, and how they compare
+/// to the expected answers.
+pub struct WatchTable<'a>(pub &'a [WatchRow<'a>]);
+
+impl Display for WatchTable<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut table = Table::new(["Input", "Part 1", "Part 2", "Elapsed"]);
+        for row in self.0 {
+            if let Some(error) = &row.current.error {
+                table.rows.push([
+                    row.label.to_string(),
+                    format!("error: {}", error),
+                    String::new(),
+                    String::new(),
+                ]);
+                continue;
+            }
+
+            let part = |i: usize| {
+                part_cell(
+                    row.current.parts[i].as_deref(),
+                    row.previous.map(|previous| previous.parts[i].as_deref()),
+                    row.expected.and_then(|expected| expected[i].as_deref()),
+                )
+            };
+            let mut elapsed = row
+                .current
+                .elapsed
+                .map(|elapsed| format!("{:?}", elapsed))
+                .unwrap_or_default();
+            if let Some(previous) = row.previous.and_then(|previous| previous.elapsed) {
+                elapsed += &format!(" (was {:?})", previous);
+            }
+            table
+                .rows
+                .push([row.label.to_string(), part(0), part(1), elapsed]);
+        }
+        table.write(f)
+    }
+}
+
+/// Formats a part's answer, with the previous answer if it changed and whether it matches the
+/// expected answer.
+fn part_cell(
+    current: Option<&str>,
+    previous: Option<Option<&str>>,
+    expected: Option<&str>,
+) -> String {
+    let mut cell = current.unwrap_or("-").to_string();
+    if let Some(previous) = previous.filter(|&previous| previous != current) {
+        cell += &format!(" (was {})", previous.unwrap_or("-"));
+    }
+    match expected {
+        Some(expected) if Some(expected) == current => cell += " ✓",
+        Some(expected) => cell += &format!(" ✗ expected {}", expected),
+        None => {}
+    }
+    cell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::part_cell;
+
+    #[test]
+    fn test_part_cell() {
+        assert_eq!(part_cell(Some("11"), None, None), "11");
+        assert_eq!(part_cell(Some("11"), Some(Some("11")), Some("11")), "11 ✓");
+        assert_eq!(
+            part_cell(Some("12"), Some(Some("11")), Some("11")),
+            "12 (was 11) ✗ expected 11"
+        );
+        assert_eq!(part_cell(Some("7"), Some(None), None), "7 (was -)");
+    }
+}
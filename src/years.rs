@@ -0,0 +1,99 @@
+//! The solutions of every year, e.g. `years::y2024::day01`, and a registry of them keyed by
+//! `(year, day)`.
+
+use crate::days::{DaySolver, Variant};
+use crate::error::{Error, Result};
+
+macro_rules! impl_years {
+    ($($y:ident => $year:literal { $($m:ident = $day:literal => $path:literal,)* },)*) => {
+        $(pub mod $y {
+            use crate::days::{run, Day, DaySolver, Variant};
+            use crate::error::{Error, Result};
+
+            pub const YEAR: usize = $year;
+
+            $(#[path = $path] pub mod $m;)*
+
+            $(const _: () = assert!(
+                $m::DAY == $day,
+                concat!("`DAY` of ", $path, " does not match its file name"),
+            );)*
+
+            pub fn days() -> Vec<usize> {
+                vec![$($m::DAY,)*]
+            }
+
+            pub fn solver(day: usize) -> Result<DaySolver> {
+                match day {
+                    $($m::DAY => Ok(|input, params, parts| {
+                        run::<$m::Solution>(input, params, parts)
+                            .map_err(|err| err.with_day($m::DAY))
+                    }),)*
+                    _ => Err(Error::new("not yet implemented").with_day(day)),
+                }
+            }
+
+            pub fn variants(day: usize) -> &'static [Variant] {
+                match day {
+                    $($m::DAY => <$m::Solution as Day>::VARIANTS,)*
+                    _ => &[],
+                }
+            }
+        })*
+
+        /// The years that have solutions, in order.
+        pub fn years() -> Vec<usize> {
+            vec![$($y::YEAR,)*]
+        }
+
+        /// The implemented days of the given year, in order.
+        pub fn days(year: usize) -> Vec<usize> {
+            match year {
+                $($y::YEAR => $y::days(),)*
+                _ => vec![],
+            }
+        }
+
+        pub fn solver(year: usize, day: usize) -> Result<DaySolver> {
+            match year {
+                $($y::YEAR => $y::solver(day),)*
+                _ => Err(Error::new(format!("no solutions for {}", year)).with_day(day)),
+            }
+        }
+
+        /// The named variants of the solution of `day` of `year`, besides the solution itself.
+        pub fn variants(year: usize, day: usize) -> &'static [Variant] {
+            match year {
+                $($y::YEAR => $y::variants(day),)*
+                _ => &[],
+            }
+        }
+    };
+}
+
+// Generated by `build.rs` from the `yYYYY/dayNN.rs` files in `src/years/`.
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+
+/// Every implemented `(year, day)`, in order.
+pub fn all_days() -> Vec<(usize, usize)> {
+    years()
+        .into_iter()
+        .flat_map(|year| days(year).into_iter().map(move |day| (year, day)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_days, solver, variants, y2024};
+
+    #[test]
+    fn test_registry() {
+        assert!(all_days().contains(&(2024, 1)));
+        assert!(solver(2024, 1).is_ok());
+        assert!(solver(2024, 26).is_err());
+        assert!(solver(1999, 1).is_err());
+        assert_eq!(y2024::YEAR, 2024);
+        assert_eq!(variants(2024, 11)[0].name, "naive");
+        assert!(variants(2024, 1).is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::days::Day;
+use crate::error::Result;
+
+pub const DAY: usize = 0;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = &'a str;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Option<Self::Output1> {
+        None
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Option<Self::Output2> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+
+    #[test]
+    fn test_solve() {
+        let example_input = "";
+        assert_eq!(Solution::solve(example_input), Ok((None, None)));
+    }
+}
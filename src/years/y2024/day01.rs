@@ -0,0 +1,95 @@
+use crate::days::Day;
+use crate::error::{parse_token, Error, Result};
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+pub const DAY: usize = 1;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (mut left_list, mut right_list) = parse(input)?;
+
+        left_list.sort();
+        right_list.sort();
+
+        Ok((left_list, right_list))
+    }
+
+    fn part1((left_list, right_list): &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            left_list
+                .iter()
+                .zip(right_list)
+                .map(|(&l, &r)| l.abs_diff(r))
+                .sum(),
+        )
+    }
+
+    fn part2((left_list, right_list): &Self::Input<'_>) -> Option<Self::Output2> {
+        let mut left_it = left_list.iter().peekable();
+        let mut right_it = right_list.iter().dedup_with_count().peekable();
+
+        let mut output = 0;
+
+        while let (Some(&l), Some(&(r_count, r))) = (left_it.peek(), right_it.peek()) {
+            match l.cmp(r) {
+                Ordering::Less => {
+                    left_it.next();
+                }
+                Ordering::Equal => {
+                    output += l * r_count;
+                    left_it.next();
+                }
+                Ordering::Greater => {
+                    right_it.next();
+                }
+            }
+        }
+
+        Some(output)
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<usize>, Vec<usize>)> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+
+    for (y, line) in input.lines().enumerate() {
+        let mut it = line
+            .split_whitespace()
+            .map(|x| parse_token::<usize>(input, x));
+        let (Some(left), Some(right), None) = (it.next(), it.next(), it.next()) else {
+            return Err(Error::at(y + 1, 1, "expected two numbers"));
+        };
+        left_list.push(left?);
+        right_list.push(right?);
+    }
+
+    Ok((left_list, right_list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example_input = indoc! {"
+            3   4
+            4   3
+            2   5
+            1   3
+            3   9
+            3   3
+        "};
+        assert_eq!(Solution::solve(example_input), Ok((Some(11), Some(31))));
+    }
+}
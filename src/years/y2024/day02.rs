@@ -1,36 +1,51 @@
+use crate::days::Day;
+use crate::error::{parse_token, Result};
 use std::cmp::Ordering;
 
 pub const DAY: usize = 2;
 
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    let mut count1 = 0;
-    let mut count2 = 0;
+pub struct Solution;
 
-    let mut levels = vec![];
-    for line in input.lines() {
-        levels.clear();
-        levels.extend(
-            line.split_whitespace()
-                .map(|level| level.parse::<u32>().unwrap()),
-        );
+impl Day for Solution {
+    type Input<'a> = Vec<Vec<u32>>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-        match check_safety_with_dampener(&levels) {
-            Ok(false) => {
-                // Safe without any removed levels.
-                count1 += 1;
-                count2 += 1;
-            }
-            Ok(true) => {
-                // Safe with a removed level.
-                count2 += 1;
-            }
-            _ => {
-                // Unsafe.
-            }
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|level| parse_token::<u32>(input, level))
+                    .collect()
+            })
+            .collect()
     }
 
-    (Some(count1), Some(count2))
+    fn part1(reports: &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            reports
+                .iter()
+                .filter(|levels| {
+                    // Safe without any removed levels.
+                    check_safety_ordered(levels, Ordering::Less).is_ok()
+                        || check_safety_ordered(levels, Ordering::Greater).is_ok()
+                })
+                .count(),
+        )
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> Option<Self::Output2> {
+        Some(
+            reports
+                .iter()
+                .filter(|levels| {
+                    // Safe with or without a removed level.
+                    check_safety_with_dampener(levels).is_ok()
+                })
+                .count(),
+        )
+    }
 }
 
 fn check_safety_with_dampener(levels: &[u32]) -> Result<bool, ()> {
@@ -95,7 +110,8 @@ fn is_safe_step(level: u32, next_level: u32, target_ordering: Ordering) -> bool
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -108,6 +124,6 @@ mod tests {
             8 6 4 4 1
             1 3 6 7 9
         "};
-        assert_eq!(solve(example_input), (Some(2), Some(4)));
+        assert_eq!(Solution::solve(example_input), Ok((Some(2), Some(4))));
     }
 }
@@ -0,0 +1,102 @@
+use crate::days::Day;
+use crate::error::{Error, Result};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take};
+use nom::character::complete::digit1;
+use nom::combinator::{map, map_res, value};
+use nom::multi::{fold_many0, many_till};
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+
+pub const DAY: usize = 3;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Vec<Instruction>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let result: IResult<_, _> = fold_many0(
+            many_till(value((), take(1usize)), parse_instruction),
+            Vec::new,
+            |mut instructions, (_, instruction)| {
+                instructions.push(instruction);
+                instructions
+            },
+        )(input);
+        let (_, instructions) =
+            result.map_err(|err| Error::new(format!("invalid program: {}", err)))?;
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            instructions
+                .iter()
+                .map(|instruction| match instruction {
+                    Instruction::Mul(x, y) => x * y,
+                    _ => 0,
+                })
+                .sum(),
+        )
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Option<Self::Output2> {
+        let mut sum = 0;
+        let mut enabled = true;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Mul(x, y) if enabled => sum += x * y,
+                Instruction::Mul(_, _) => {}
+                Instruction::Enable => enabled = true,
+                Instruction::Disable => enabled = false,
+            }
+        }
+
+        Some(sum)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Enable,
+    Disable,
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        map(
+            delimited(
+                tag("mul("),
+                separated_pair(
+                    map_res(digit1, |s: &str| s.parse::<u32>()),
+                    tag(","),
+                    map_res(digit1, |s: &str| s.parse::<u32>()),
+                ),
+                tag(")"),
+            ),
+            |(x, y)| Instruction::Mul(x, y),
+        ),
+        value(Instruction::Enable, tag("do()")),
+        value(Instruction::Disable, tag("don't()")),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+
+    #[test]
+    fn test_solve() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(Solution::solve(input), Ok((Some(161), Some(161))));
+
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(Solution::solve(input), Ok((Some(161), Some(48))));
+    }
+}
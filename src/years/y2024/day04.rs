@@ -0,0 +1,118 @@
+use crate::days::Day;
+use crate::error::Result;
+use crate::util::grid::StrGrid;
+
+pub const DAY: usize = 4;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = &'a [u8];
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(StrGrid::new(input)?.data())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Option<Self::Output1> {
+        let width = input.iter().position(|&c| c == b'\n').unwrap();
+        let line_width = width + 1;
+
+        let mut count1 = 0;
+
+        for index in 0..input.len() {
+            let target = match input[index] {
+                b'X' => [b'M', b'A', b'S'],
+                b'S' => [b'A', b'M', b'X'],
+                _ => continue,
+            };
+
+            for offset in [
+                1,              // right
+                line_width - 1, // down left
+                line_width,     // down
+                line_width + 1, // down right
+            ] {
+                // Break if out of bounds (any next offset will also be out of bounds).
+                if index + offset * 3 >= input.len() {
+                    break;
+                }
+
+                // Note: wrapping around is fine, since then '\n' will be included in the string,
+                // so it will never match.
+
+                // `offset * 1` lines up with the other characters.
+                #[allow(clippy::identity_op)]
+                if input[index + offset * 1] == target[0]
+                    && input[index + offset * 2] == target[1]
+                    && input[index + offset * 3] == target[2]
+                {
+                    count1 += 1;
+                }
+            }
+        }
+
+        Some(count1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Self::Output2> {
+        let width = input.iter().position(|&c| c == b'\n').unwrap();
+        let height = input.len() / (width + 1);
+
+        let char_at = |x: isize, y: isize| -> char {
+            let index = x + y * (width as isize + 1);
+            input[index as usize] as char
+        };
+
+        let mut count2 = 0;
+
+        for y in 1..height as isize - 1 {
+            for x in 1..width as isize - 1 {
+                if char_at(x, y) != 'A' {
+                    continue;
+                }
+                if !matches!(
+                    [char_at(x + 1, y + 1), char_at(x - 1, y - 1)],
+                    ['M', 'S'] | ['S', 'M']
+                ) {
+                    continue;
+                }
+                if !matches!(
+                    [char_at(x - 1, y + 1), char_at(x + 1, y - 1)],
+                    ['M', 'S'] | ['S', 'M']
+                ) {
+                    continue;
+                }
+
+                count2 += 1;
+            }
+        }
+
+        Some(count2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example_input = indoc! {"
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        "};
+        assert_eq!(Solution::solve(example_input), Ok((Some(18), Some(9))));
+    }
+}
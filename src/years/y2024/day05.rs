@@ -0,0 +1,130 @@
+use crate::days::Day;
+use crate::error::{parse_token, Error, Result};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+pub const DAY: usize = 5;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = (Rules, Vec<Vec<u32>>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut lines = input.lines();
+
+        // Parse rules
+        let mut rules = Rules::new();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            let (page_a, page_b) = line
+                .split_once('|')
+                .ok_or_else(|| Error::at_str(input, line, "expected a rule like '47|53'"))?;
+            rules
+                .entry(parse_token(input, page_a)?)
+                .or_default()
+                .push(parse_token(input, page_b)?);
+        }
+
+        // Parse updates
+        let updates = lines
+            .map(|line| {
+                line.split(',')
+                    .map(|s| parse_token::<u32>(input, s))
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            updates
+                .iter()
+                .filter(|pages| is_sorted(rules, pages))
+                .map(|pages| pages[pages.len() / 2])
+                .sum(),
+        )
+    }
+
+    fn part2((rules, updates): &Self::Input<'_>) -> Option<Self::Output2> {
+        Some(
+            updates
+                .iter()
+                .filter(|pages| !is_sorted(rules, pages))
+                .map(|pages| {
+                    let mut pages = pages.clone();
+                    pages.sort_by(|a, b| cmp(rules, *a, *b));
+                    pages[pages.len() / 2]
+                })
+                .sum(),
+        )
+    }
+}
+
+pub type Rules = HashMap<u32, Vec<u32>>;
+
+fn cmp(rules: &Rules, a: u32, b: u32) -> Ordering {
+    debug_assert_ne!(a, b);
+    if rules.get(&a).map(|x| x.contains(&b)).unwrap_or(false) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+fn is_sorted(rules: &Rules, pages: &[u32]) -> bool {
+    pages
+        .iter()
+        .tuple_combinations()
+        .all(|(a, b)| cmp(rules, *a, *b) == Ordering::Less)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example = indoc! {"
+            47|53
+            97|13
+            97|61
+            97|47
+            75|29
+            61|13
+            75|53
+            29|13
+            97|29
+            53|29
+            61|53
+            97|53
+            61|29
+            47|13
+            75|47
+            97|75
+            47|61
+            75|61
+            47|29
+            75|13
+            53|13
+            
+            75,47,61,53,29
+            97,61,53,29,13
+            75,29,13
+            75,97,47,61,53
+            61,13,29
+            97,13,75,29,47
+        "};
+        assert_eq!(Solution::solve(example), Ok((Some(143), Some(123))))
+    }
+}
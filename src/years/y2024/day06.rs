@@ -1,22 +1,51 @@
+use crate::days::Day;
+use crate::error::{Error, Result};
+use crate::util::grid::{Grid, StrGrid};
 use nom::InputIter;
 use std::collections::HashSet;
 
 pub const DAY: usize = 6;
 
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    let input = input.as_bytes();
-    let width = input.position(|b| b == b'\n').unwrap();
-    let line_width = width + 1;
-    let height = input.len() / line_width;
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Map<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let grid = StrGrid::new(input)?;
+        let width = grid.width();
+        let line_width = width + 1;
+        let height = grid.height();
+        let input = grid.data();
+
+        // Find guard character (^)
+        let guard_index = input
+            .position(|b| b == b'^')
+            .ok_or_else(|| Error::new("no guard (^) found"))?;
+
+        Ok(Map {
+            input,
+            width,
+            line_width,
+            height,
+            init_guard_x: guard_index % line_width,
+            init_guard_y: guard_index / line_width,
+        })
+    }
 
-    // Find guard character (^)
-    let guard_index = input.position(|b| b == b'^').unwrap();
-    let init_guard_x = guard_index % line_width;
-    let init_guard_y = guard_index / line_width;
-    let init_guard_dir = Direction::North;
+    fn part1(map: &Self::Input<'_>) -> Option<Self::Output1> {
+        let &Map {
+            input,
+            width,
+            line_width,
+            height,
+            init_guard_x,
+            init_guard_y,
+        } = map;
+        let init_guard_dir = Direction::North;
 
-    // Part 1
-    let count1 = {
         let mut guard_x = init_guard_x;
         let mut guard_y = init_guard_y;
         let mut guard_dir = init_guard_dir;
@@ -47,11 +76,20 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
             }
         }
 
-        count
-    };
+        Some(count)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Option<Self::Output2> {
+        let &Map {
+            input,
+            width,
+            line_width,
+            height,
+            init_guard_x,
+            init_guard_y,
+        } = map;
+        let init_guard_dir = Direction::North;
 
-    // Part 2
-    let count2 = {
         let mut count = 0;
 
         for obstruction_y in 0..height {
@@ -98,10 +136,17 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
             }
         }
 
-        count
-    };
+        Some(count)
+    }
+}
 
-    (Some(count1), Some(count2))
+pub struct Map<'a> {
+    input: &'a [u8],
+    width: usize,
+    line_width: usize,
+    height: usize,
+    init_guard_x: usize,
+    init_guard_y: usize,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -125,7 +170,8 @@ impl Direction {
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -142,6 +188,9 @@ mod tests {
             #.........
             ......#...
         "};
-        assert_eq!(solve(example_input), (Some(41) /* Heh, Sum 41! */, Some(6)));
+        assert_eq!(
+            Solution::solve(example_input),
+            Ok((Some(41) /* Heh, Sum 41! */, Some(6)))
+        );
     }
 }
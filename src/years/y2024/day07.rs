@@ -0,0 +1,109 @@
+use crate::days::Day;
+use crate::error::{parse_all, Result};
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::map_res;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+pub const DAY: usize = 7;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Vec<(isize, Vec<isize>)>;
+    type Output1 = isize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|line| parse_all(input, line, parse_line, "an equation like '190: 10 19'"))
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            equations
+                .iter()
+                .filter(|(test_value, numbers)| test(*test_value, numbers, 0).0)
+                .map(|(test_value, _)| test_value)
+                .sum(),
+        )
+    }
+
+    fn part2(equations: &Self::Input<'_>) -> Option<Self::Output2> {
+        Some(
+            equations
+                .iter()
+                .filter(|(test_value, numbers)| test(*test_value, numbers, 0).1)
+                .map(|(test_value, _)| test_value)
+                .sum(),
+        )
+    }
+}
+
+/// Tests whether the numbers can be combined into the test value, returning whether that is
+/// possible with only addition and multiplication (part 1) and whether it is possible when
+/// concatenation is also allowed (part 2).
+fn test(test_value: isize, numbers: &[isize], accumulator: isize) -> (bool, bool) {
+    if numbers.is_empty() {
+        let result = accumulator == test_value;
+        (result, result)
+    } else {
+        let result_add = test(test_value, &numbers[1..], accumulator + numbers[0]);
+        if result_add.0 {
+            return (true, true);
+        }
+        if result_add.1 {
+            return (false, true);
+        }
+
+        let result_mul = test(test_value, &numbers[1..], accumulator * numbers[0]);
+        if result_mul.0 {
+            return (true, true);
+        }
+        if result_mul.1 {
+            return (false, true);
+        }
+
+        let result_concat = test(test_value, &numbers[1..], concat(accumulator, numbers[0]));
+        (false, result_concat.1)
+    }
+}
+
+fn concat(a: isize, b: isize) -> isize {
+    a * 10isize.pow(b.ilog10() + 1) + b
+}
+
+fn parse_line(line: &str) -> IResult<&str, (isize, Vec<isize>)> {
+    separated_pair(
+        map_res(digit1, |x: &str| x.parse::<isize>()),
+        tag(": "),
+        separated_list0(tag(" "), map_res(digit1, |x: &str| x.parse::<isize>())),
+    )(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let input = indoc! {"
+            190: 10 19
+            3267: 81 40 27
+            83: 17 5
+            156: 15 6
+            7290: 6 8 6 15
+            161011: 16 10 13
+            192: 17 8 14
+            21037: 9 7 18 13
+            292: 11 6 16 20
+        "};
+        assert_eq!(Solution::solve(input), Ok((Some(3749), Some(11387))));
+    }
+}
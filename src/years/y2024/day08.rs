@@ -0,0 +1,127 @@
+use crate::days::Day;
+use crate::error::Result;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+pub const DAY: usize = 8;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Antennas;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut antennas_map = HashMap::<char, Vec<(isize, isize)>>::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            width = line.len();
+            height += 1;
+
+            for (x, c) in line.chars().enumerate() {
+                if c == '.' {
+                    continue;
+                }
+
+                antennas_map
+                    .entry(c)
+                    .or_default()
+                    .push((x as isize, y as isize));
+            }
+        }
+        Ok(Antennas {
+            antennas_map,
+            x_range: 0..width as isize,
+            y_range: 0..height as isize,
+        })
+    }
+
+    fn part1(antennas: &Self::Input<'_>) -> Option<Self::Output1> {
+        let mut antinodes = HashSet::<(isize, isize)>::new();
+        antennas.for_each_pair(|x, y, dx, dy| {
+            let xi = x + dx;
+            let yi = y + dy;
+            if antennas.contains(xi, yi) {
+                antinodes.insert((xi, yi));
+            }
+        });
+        Some(antinodes.len())
+    }
+
+    fn part2(antennas: &Self::Input<'_>) -> Option<Self::Output2> {
+        let mut antinodes = HashSet::<(isize, isize)>::new();
+        antennas.for_each_pair(|x, y, dx, dy| {
+            let d = gcd::euclid_usize(dx.unsigned_abs(), dy.unsigned_abs()) as isize;
+            let dx = dx / d;
+            let dy = dy / d;
+            for i in 0.. {
+                let xi = x + dx * i;
+                let yi = y + dy * i;
+                if antennas.contains(xi, yi) {
+                    antinodes.insert((xi, yi));
+                } else {
+                    break;
+                }
+            }
+        });
+        Some(antinodes.len())
+    }
+}
+
+pub struct Antennas {
+    antennas_map: HashMap<char, Vec<(isize, isize)>>,
+    x_range: Range<isize>,
+    y_range: Range<isize>,
+}
+
+impl Antennas {
+    fn contains(&self, x: isize, y: isize) -> bool {
+        self.x_range.contains(&x) && self.y_range.contains(&y)
+    }
+
+    /// Calls `f(x, y, dx, dy)` for both antennas of every pair with the same frequency, where
+    /// `(dx, dy)` points away from the other antenna.
+    fn for_each_pair(&self, mut f: impl FnMut(isize, isize, isize, isize)) {
+        for antennas in self.antennas_map.values() {
+            antennas
+                .iter()
+                .tuple_combinations()
+                .for_each(|(&(x0, y0), &(x1, y1))| {
+                    let dx = x0 - x1;
+                    let dy = y0 - y1;
+                    f(x0, y0, dx, dy);
+                    f(x1, y1, -dx, -dy);
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example_input = indoc! {"
+            ............
+            ........0...
+            .....0......
+            .......0....
+            ....0.......
+            ......A.....
+            ............
+            ............
+            ........A...
+            .........A..
+            ............
+            ............
+        "};
+        assert_eq!(Solution::solve(example_input), Ok((Some(14), Some(34))));
+    }
+}
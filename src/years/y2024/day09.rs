@@ -1,4 +1,5 @@
-use itertools::Itertools;
+use crate::days::Day;
+use crate::error::{Error, Result};
 
 pub const DAY: usize = 9;
 
@@ -24,16 +25,31 @@ impl Blocks {
     }
 }
 
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    let input = input
-        .lines()
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as usize)
-        .collect_vec();
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let disk_map = input
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| Error::at(1, 1, "expected a disk map"))?;
+
+        disk_map
+            .char_indices()
+            .map(|(x, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as usize)
+                    .ok_or_else(|| Error::at(1, x + 1, format!("invalid digit '{}'", c)))
+            })
+            .collect()
+    }
 
-    let checksum_1 = {
+    fn part1(input: &Self::Input<'_>) -> Option<Self::Output1> {
         let mut back_index = input.len() - 1;
         let mut back_blocks = input[back_index];
 
@@ -74,10 +90,12 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
             blocks.push(block_id);
         }
 
-        blocks.checksum
-    };
+        Some(blocks.checksum)
+    }
 
-    let checksum_2 = {
+    fn part2(input: &Self::Input<'_>) -> Option<Self::Output2> {
+        // A file for every even index.
+        #[allow(clippy::manual_div_ceil)]
         let num_files = (input.len() + 1) / 2;
 
         // Files marked as moved, so they'll be skipped for regular placement.
@@ -124,19 +142,18 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
             }
         }
 
-        blocks.checksum
-    };
-
-    (Some(checksum_1), Some(checksum_2))
+        Some(blocks.checksum)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
 
     #[test]
     fn test_solve() {
         let example_input = "2333133121414131402";
-        assert_eq!(solve(example_input), (Some(1928), Some(2858)));
+        assert_eq!(Solution::solve(example_input), Ok((Some(1928), Some(2858))));
     }
 }
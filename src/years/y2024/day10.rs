@@ -1,13 +1,35 @@
+use crate::days::Day;
+use crate::error::{Error, Result};
 use crate::util::grid::{Coord, Direction, Grid, StrGrid, VecGrid};
 use std::collections::VecDeque;
 
 pub const DAY: usize = 10;
 
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    let heights = StrGrid::new(input.as_bytes()).map(|&c| (c as char).to_digit(10).unwrap());
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = StrGrid<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let grid = StrGrid::new(input)?;
+        if let Some((x, y)) = grid
+            .iter()
+            .find_map(|(coord, c)| (!c.is_ascii_digit()).then_some(coord))
+        {
+            return Err(Error::at(
+                y + 1,
+                x + 1,
+                format!("invalid height '{}'", grid[(x, y)] as char),
+            ));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&grid: &Self::Input<'_>) -> Option<Self::Output1> {
+        let heights = grid.map(|&c| (c as char).to_digit(10).unwrap());
 
-    // Part 1
-    let count1 = {
         let mut count1 = 0;
         for (coord, height) in heights.iter() {
             if height == 0 {
@@ -15,11 +37,12 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
                 count1 += count_trails(&heights, coord);
             }
         }
-        count1
-    };
+        Some(count1)
+    }
+
+    fn part2(&grid: &Self::Input<'_>) -> Option<Self::Output2> {
+        let heights = grid.map(|&c| (c as char).to_digit(10).unwrap());
 
-    // Part 2
-    let count2 = {
         let mut counts =
             VecGrid::from_data(heights.width(), vec![0; heights.width() * heights.height()]);
         let mut queued = VecGrid::from_data(
@@ -59,10 +82,8 @@ pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
             }
         }
 
-        count2
-    };
-
-    (Some(count1), Some(count2))
+        Some(count2)
+    }
 }
 
 fn count_trails<'a>(grid: &'a impl Grid<'a, Item = u32>, trailhead_coord: Coord) -> usize {
@@ -99,7 +120,8 @@ fn count_trails<'a>(grid: &'a impl Grid<'a, Item = u32>, trailhead_coord: Coord)
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -114,6 +136,6 @@ mod tests {
             01329801
             10456732
         "};
-        assert_eq!(solve(example_input), (Some(36), Some(81)));
+        assert_eq!(Solution::solve(example_input), Ok((Some(36), Some(81))));
     }
 }
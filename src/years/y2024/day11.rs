@@ -0,0 +1,194 @@
+use crate::days::{Day, Variant};
+use crate::error::{parse_token, Result};
+use crate::params::{Param, Params};
+use std::collections::HashMap;
+
+pub const DAY: usize = 11;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Stones;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("part1-blinks", 25),
+        Param::new("part2-blinks", 75),
+    ];
+
+    const VARIANTS: &'static [Variant] = &[Variant::new::<Naive>("naive")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        Ok(Stones {
+            stones: input
+                .split_whitespace()
+                .map(|s| parse_token::<u64>(input, s))
+                .collect::<Result<_>>()?,
+            blinks: [params.get("part1-blinks")?, params.get("part2-blinks")?],
+        })
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> Option<Self::Output1> {
+        let mut cache = HashMap::default();
+        Some(
+            stones
+                .stones
+                .iter()
+                .map(|&stone| blink(stone, stones.blinks[0].into(), &mut cache))
+                .sum(),
+        )
+    }
+
+    fn part2(stones: &Self::Input<'_>) -> Option<Self::Output2> {
+        let mut cache = HashMap::default();
+        Some(
+            stones
+                .stones
+                .iter()
+                .map(|&stone| blink(stone, stones.blinks[1].into(), &mut cache))
+                .sum(),
+        )
+    }
+}
+
+pub struct Stones {
+    stones: Vec<u64>,
+    /// How many times to blink in each part, at most 255 to fit in the cache key of `blink`.
+    blinks: [u8; 2],
+}
+
+fn blink(stone: u64, times: u64, cache: &mut HashMap<u64, u64>) -> u64 {
+    if times == 0 {
+        1
+    } else {
+        let cache_key = stone << 8 | times;
+        if let Some(&result) = cache.get(&cache_key) {
+            return result;
+        }
+
+        let result = match stone {
+            0 => blink(1, times - 1, cache),
+            n if (n.ilog10() + 1) % 2 == 0 => {
+                // Half the number of digits.
+                #[allow(clippy::manual_div_ceil)]
+                let f = 10u64.pow((n.ilog10() + 1) / 2);
+                blink(n / f, times - 1, cache) + blink(n % f, times - 1, cache)
+            }
+            n => blink(n * 2024, times - 1, cache),
+        };
+        cache.insert(cache_key, result);
+        result
+    }
+}
+
+/// Simulates every stone, which is only feasible for part 1.
+pub struct Naive;
+
+impl Day for Naive {
+    type Input<'a> = Stones;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PARAMS: &'static [Param] = Solution::PARAMS;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Solution::parse(input)
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        Solution::parse_with(input, params)
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> Option<Self::Output1> {
+        let blinks = stones.blinks[0];
+        let mut stones = stones.stones.clone();
+        let mut next_stones = vec![];
+        for _ in 0..blinks {
+            blink_naive(&stones, &mut next_stones);
+            (next_stones, stones) = (stones, next_stones);
+        }
+        Some(stones.len())
+    }
+
+    fn part2(_stones: &Self::Input<'_>) -> Option<Self::Output2> {
+        None
+    }
+}
+
+fn blink_naive(stones: &Vec<u64>, next_stones: &mut Vec<u64>) {
+    next_stones.clear();
+
+    for &stone in stones {
+        match stone {
+            0 => next_stones.push(1),
+            n if (n.ilog10() + 1) % 2 == 0 => {
+                // Half the number of digits.
+                #[allow(clippy::manual_div_ceil)]
+                let f = 10u64.pow((n.ilog10() + 1) / 2);
+                next_stones.push(n / f);
+                next_stones.push(n % f);
+            }
+            n => next_stones.push(n * 2024),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blink_naive, Naive, Solution};
+    use crate::days::Day;
+
+    #[test]
+    fn test_solve() {
+        let example_input = "125 17";
+        assert_eq!(
+            Solution::solve(example_input),
+            Ok((Some(55312), Some(65601038650482)))
+        );
+        assert_eq!(Naive::solve(example_input), Ok((Some(55312), None)));
+    }
+
+    #[test]
+    fn test_blink() {
+        let mut stones = vec![125, 17];
+        let mut next_stones = vec![];
+
+        blink_naive(&stones, &mut next_stones);
+        (next_stones, stones) = (stones, next_stones);
+        assert_eq!(stones, vec![253000, 1, 7]);
+
+        blink_naive(&stones, &mut next_stones);
+        (next_stones, stones) = (stones, next_stones);
+        assert_eq!(stones, vec![253, 0, 2024, 14168]);
+
+        blink_naive(&stones, &mut next_stones);
+        (next_stones, stones) = (stones, next_stones);
+        assert_eq!(stones, vec![512072, 1, 20, 24, 28676032]);
+
+        blink_naive(&stones, &mut next_stones);
+        (next_stones, stones) = (stones, next_stones);
+        assert_eq!(stones, vec![512, 72, 2024, 2, 0, 2, 4, 2867, 6032]);
+
+        blink_naive(&stones, &mut next_stones);
+        (next_stones, stones) = (stones, next_stones);
+        assert_eq!(
+            stones,
+            vec![1036288, 7, 2, 20, 24, 4048, 1, 4048, 8096, 28, 67, 60, 32]
+        );
+
+        blink_naive(&stones, &mut next_stones);
+        (_, stones) = (stones, next_stones);
+        assert_eq!(
+            stones,
+            vec![
+                2097446912, 14168, 4048, 2, 0, 2, 4, 40, 48, 2024, 40, 48, 80, 96, 2, 8, 6, 7, 6,
+                0, 3, 2
+            ]
+        );
+    }
+}
@@ -1,26 +1,49 @@
+use crate::days::Day;
+use crate::error::Result;
 use crate::util::grid::{Coord, Grid, StrGrid, VecGrid};
 use std::collections::VecDeque;
 
 pub const DAY: usize = 12;
 
-pub fn solve(input: &str) -> (Option<u32>, Option<u32>) {
-    let grid = StrGrid::new(input.as_bytes());
+pub struct Solution;
 
+impl Day for Solution {
+    type Input<'a> = StrGrid<'a>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        StrGrid::new(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            fill_regions(grid)
+                .map(|(area, perimeter, _)| area * perimeter)
+                .sum(),
+        )
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Option<Self::Output2> {
+        Some(
+            fill_regions(grid)
+                .map(|(area, _, corners)| area * corners)
+                .sum(),
+        )
+    }
+}
+
+/// Returns the `(area, perimeter, corners)` of each region in the grid.
+fn fill_regions<'g>(grid: &'g StrGrid<'g>) -> impl Iterator<Item = (u32, u32, u32)> + 'g {
     let mut visited = VecGrid::from_data(grid.width(), vec![false; grid.width() * grid.height()]);
-    let mut count1 = 0;
-    let mut count2 = 0;
 
-    for (coord, _) in grid.iter() {
+    grid.iter().filter_map(move |(coord, _)| {
         if visited[coord] {
-            continue;
+            return None;
         }
 
-        let (area, perimeter, corners) = fill_region(&grid, coord, &mut visited);
-        count1 += area * perimeter;
-        count2 += area * corners;
-    }
-
-    (Some(count1), Some(count2))
+        Some(fill_region(grid, coord, &mut visited))
+    })
 }
 
 fn fill_region<'g>(
@@ -94,7 +117,8 @@ fn fill_region<'g>(
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -111,6 +135,6 @@ mod tests {
             MIIISIJEEE
             MMMISSJEEE
         "};
-        assert_eq!(solve(example_input), (Some(1930), Some(1206)));
+        assert_eq!(Solution::solve(example_input), Ok((Some(1930), Some(1206))));
     }
 }
@@ -1,40 +1,85 @@
+use crate::days::Day;
+use crate::error::{parse_all, Result};
+use crate::params::{Param, Params};
 use divrem::DivRem;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{digit1, newline};
 use nom::combinator::{map_res, opt};
+use nom::multi::many0;
 use nom::sequence::{delimited, preceded, separated_pair};
 use nom::IResult;
 
 pub const DAY: usize = 13;
 
-pub fn solve(input: &str) -> (Option<isize>, Option<isize>) {
-    let mut input = input;
+pub struct Solution;
 
-    let mut count1 = 0;
-    let mut count2 = 0;
+impl Day for Solution {
+    type Input<'a> = Arcade;
+    type Output1 = isize;
+    type Output2 = isize;
 
-    while let Ok((next_input, mut claw_machine)) = parse_claw_machine(input) {
-        input = next_input;
+    const PARAMS: &'static [Param] = &[
+        // Added to the prize coordinates in part 2.
+        Param::new("offset", 10000000000000),
+        // Tokens it costs to push each button.
+        Param::new("a-cost", 3),
+        Param::new("b-cost", 1),
+    ];
 
-        // Part 1
-        if let Some(cost) = solve_claw_machine(claw_machine) {
-            count1 += cost;
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        let claw_machines = parse_all(
+            input,
+            input,
+            many0(parse_claw_machine),
+            "a claw machine like 'Button A: X+94, Y+34'",
+        )?;
+
+        Ok(Arcade {
+            claw_machines,
+            offset: params.get("offset")?,
+            costs: (params.get("a-cost")?, params.get("b-cost")?),
+        })
+    }
 
-        // Part 2
-        claw_machine.prize.0 += 10000000000000;
-        claw_machine.prize.1 += 10000000000000;
-        if let Some(cost) = solve_claw_machine(claw_machine) {
-            count2 += cost;
-        }
+    fn part1(arcade: &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(
+            arcade
+                .claw_machines
+                .iter()
+                .filter_map(|&claw_machine| solve_claw_machine(claw_machine, arcade.costs))
+                .sum(),
+        )
     }
 
-    (Some(count1), Some(count2))
+    fn part2(arcade: &Self::Input<'_>) -> Option<Self::Output2> {
+        Some(
+            arcade
+                .claw_machines
+                .iter()
+                .filter_map(|&claw_machine| {
+                    let mut claw_machine = claw_machine;
+                    claw_machine.prize.0 += arcade.offset;
+                    claw_machine.prize.1 += arcade.offset;
+                    solve_claw_machine(claw_machine, arcade.costs)
+                })
+                .sum(),
+        )
+    }
+}
+
+pub struct Arcade {
+    claw_machines: Vec<ClawMachine>,
+    offset: isize,
+    costs: (isize, isize),
 }
 
 #[derive(Debug, Copy, Clone)]
-struct ClawMachine {
+pub struct ClawMachine {
     button_a: (isize, isize),
     button_b: (isize, isize),
     prize: (isize, isize),
@@ -69,7 +114,7 @@ fn parse_claw_machine(input: &str) -> IResult<&str, ClawMachine> {
     ))
 }
 
-fn solve_claw_machine(claw_machine: ClawMachine) -> Option<isize> {
+fn solve_claw_machine(claw_machine: ClawMachine, costs: (isize, isize)) -> Option<isize> {
     // Solve for a, b:
     // a0 * a + b0 * b = c0
     // a1 * a + b1 * b = c1
@@ -106,12 +151,13 @@ fn solve_claw_machine(claw_machine: ClawMachine) -> Option<isize> {
         return None;
     }
 
-    Some(a * 3 + b)
+    Some(a * costs.0 + b * costs.1)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -133,6 +179,9 @@ mod tests {
             Button B: X+27, Y+71
             Prize: X=18641, Y=10279
         "};
-        assert_eq!(solve(example_input), (Some(480), Some(875318608908)));
+        assert_eq!(
+            Solution::solve(example_input),
+            Ok((Some(480), Some(875318608908)))
+        );
     }
 }
@@ -0,0 +1,213 @@
+use crate::days::Day;
+use crate::error::{parse_all, Error, Result};
+use crate::params::{Param, Params};
+use crate::util::grid::VecGrid;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::{map_res, opt, recognize};
+use nom::sequence::{preceded, separated_pair, tuple};
+use nom::IResult;
+use std::cmp::Ordering;
+
+pub const DAY: usize = 14;
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Robots;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 101),
+        Param::new("height", 103),
+        // Size of the box around the Christmas tree.
+        Param::new("tree-width", 31),
+        Param::new("tree-height", 33),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        let particles = input
+            .lines()
+            .map(|line| parse_all(input, line, parse_particle, "a robot like 'p=0,4 v=3,-3'"))
+            .collect::<Result<_>>()?;
+
+        let positive = |name: &str| match params.get::<isize>(name)? {
+            value if value > 0 => Ok(value),
+            value => Err(Error::new(format!(
+                "parameter '{}' must be positive, got {}",
+                name, value
+            ))),
+        };
+
+        Ok(Robots {
+            particles,
+            dimensions: [positive("width")?, positive("height")?],
+            tree_dimensions: [positive("tree-width")?, positive("tree-height")?],
+        })
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn part1(robots: &Self::Input<'_>) -> Option<Self::Output1> {
+        let dimensions = robots.dimensions;
+
+        let get_quadrant = |particle: Particle| -> Option<usize> {
+            match (
+                particle.pos[0].cmp(&(dimensions[0] / 2)),
+                particle.pos[1].cmp(&(dimensions[1] / 2)),
+            ) {
+                (Ordering::Less, Ordering::Less) => Some(0),
+                (Ordering::Greater, Ordering::Less) => Some(1),
+                (Ordering::Less, Ordering::Greater) => Some(2),
+                (Ordering::Greater, Ordering::Greater) => Some(3),
+                _ => None,
+            }
+        };
+
+        let mut counts = [0; 4];
+        for mut particle in robots.particles.iter().copied() {
+            for d in 0..2 {
+                particle.pos[d] =
+                    (particle.pos[d] + particle.vel[d] * 100).rem_euclid(dimensions[d]);
+            }
+
+            if let Some(quadrant) = get_quadrant(particle) {
+                counts[quadrant] += 1;
+            }
+        }
+
+        Some(counts.iter().product())
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn part2(robots: &Self::Input<'_>) -> Option<Self::Output2> {
+        let dimensions = robots.dimensions;
+        let tree_dimensions = robots.tree_dimensions;
+        let mut particles = robots.particles.clone();
+
+        let mut part_2 = None;
+
+        for time in 1..10000 {
+            let mut grid = VecGrid::from_data(
+                dimensions[0] as usize,
+                vec![false; (dimensions[0] * dimensions[1]) as usize],
+            );
+
+            for particle in &mut particles {
+                for d in 0..2 {
+                    particle.pos[d] = (particle.pos[d] + particle.vel[d]).rem_euclid(dimensions[d]);
+                }
+
+                grid[(particle.pos[0] as usize, particle.pos[1] as usize)] = true;
+            }
+
+            let mut has_tree_rect = false;
+            'outer: for particle in &particles {
+                let top_left = particle.pos;
+
+                // Check that tree rect would be in bounds
+                for d in 0..2 {
+                    if top_left[d] + tree_dimensions[d] >= dimensions[d] {
+                        continue 'outer;
+                    }
+                }
+
+                for d in 0..2 {
+                    let other_d = (d + 1) % 2;
+
+                    for i in 0..tree_dimensions[d] {
+                        // Point along top/left edges
+                        let mut p = top_left;
+                        p[d] = top_left[d] + i;
+                        if !grid[(p[0] as usize, p[1] as usize)] {
+                            continue 'outer;
+                        }
+
+                        // Point along bottom/right edges
+                        p[other_d] = top_left[other_d] + tree_dimensions[other_d] - 1;
+                        if !grid[(p[0] as usize, p[1] as usize)] {
+                            continue 'outer;
+                        }
+                    }
+                }
+
+                has_tree_rect = true;
+                break;
+            }
+
+            if has_tree_rect {
+                part_2 = Some(time as usize);
+            }
+        }
+
+        part_2
+    }
+}
+
+pub struct Robots {
+    particles: Vec<Particle>,
+    dimensions: Vec2,
+    tree_dimensions: Vec2,
+}
+
+pub type Vec2 = [isize; 2];
+
+#[derive(Debug, Copy, Clone)]
+pub struct Particle {
+    pos: Vec2,
+    vel: Vec2,
+}
+
+fn parse_particle(input: &str) -> IResult<&str, Particle> {
+    let (input, pos) = preceded(tag("p="), parse_vec2)(input)?;
+    let (input, vel) = preceded(tag(" v="), parse_vec2)(input)?;
+
+    Ok((input, Particle { pos, vel }))
+}
+
+fn parse_vec2(input: &str) -> IResult<&str, Vec2> {
+    let (input, (x, y)) = separated_pair(parse_isize, tag(","), parse_isize)(input)?;
+    Ok((input, [x, y]))
+}
+
+fn parse_isize(input: &str) -> IResult<&str, isize> {
+    map_res(recognize(tuple((opt(tag("-")), digit1))), |s: &str| {
+        s.parse::<isize>()
+    })(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use crate::params::Params;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example_input = indoc! {"
+            p=0,4 v=3,-3
+            p=6,3 v=-1,-3
+            p=10,3 v=-1,2
+            p=2,0 v=2,-1
+            p=0,0 v=1,3
+            p=3,0 v=-2,-2
+            p=7,6 v=-1,-3
+            p=3,0 v=-1,-2
+            p=9,3 v=2,3
+            p=7,3 v=-1,2
+            p=2,4 v=2,-3
+            p=9,5 v=-3,-3
+        "};
+        let params = ["width=11", "height=7", "tree-width=4", "tree-height=4"]
+            .map(|param| param.parse().unwrap());
+        let params = Params::new(Solution::PARAMS, &params).unwrap();
+        let robots = Solution::parse_with(example_input, &params).unwrap();
+        assert_eq!(Solution::part1(&robots), Some(12));
+        assert_eq!(Solution::part2(&robots), None);
+    }
+}
@@ -1,49 +1,85 @@
-use crate::util::grid::{Coord, Direction, Grid, VecGrid};
+use crate::days::Day;
+use crate::error::{Error, Result};
+use crate::util::grid::{Coord, Direction, Grid, StrGrid, VecGrid};
 
 pub const DAY: usize = 15;
 
-pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
-    let (grid_input, moves_input) = {
-        let mut split = input.splitn(2, "\n\n");
-        (split.next().unwrap(), split.next().unwrap())
-    };
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = (VecGrid<char>, Vec<Direction>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (grid_input, moves_input) = input
+            .split_once("\n\n")
+            .ok_or_else(|| Error::new("expected a blank line between the map and the moves"))?;
+
+        let grid = {
+            // Include the newline that was split off, so the last row is terminated like the rest.
+            let grid = StrGrid::new(&input[..grid_input.len() + 1])?;
+            let mut robots = 0;
+            for ((x, y), &c) in grid.iter() {
+                match c {
+                    b'.' | b'O' | b'#' => {}
+                    b'@' => robots += 1,
+                    _ => {
+                        return Err(Error::at(
+                            y + 1,
+                            x + 1,
+                            format!("unexpected '{}' in map", c as char),
+                        ))
+                    }
+                }
+            }
+            if robots != 1 {
+                return Err(Error::new(format!(
+                    "expected exactly one robot (@), found {}",
+                    robots
+                )));
+            }
 
-    let grid_1 = {
-        let width = grid_input.chars().position(|c| c == '\n').unwrap();
-        let data = grid_input
+            let data = grid_input.chars().filter(|&c| c != '\n').collect();
+            VecGrid::from_data(grid.width(), data)
+        };
+
+        let moves = moves_input
             .chars()
-            .filter(|c| matches!(c, '.' | '@' | 'O' | '#'))
-            .collect();
-        VecGrid::from_data(width, data)
-    };
-
-    let grid_2 = {
-        let width = grid_1.width() * 2;
-        let data = grid_1
-            .iter()
-            .flat_map(|(_, c)| match c {
-                '#' => ['#', '#'],
-                'O' => ['[', ']'],
-                '.' => ['.', '.'],
-                '@' => ['@', '.'],
-                _ => unreachable!(),
+            .filter_map(|c| match c {
+                '^' => Some(Direction::North),
+                'v' => Some(Direction::South),
+                '<' => Some(Direction::West),
+                '>' => Some(Direction::East),
+                _ => None,
             })
             .collect();
-        VecGrid::from_data(width, data)
-    };
-
-    let moves = moves_input.chars().filter_map(|c| match c {
-        '^' => Some(Direction::North),
-        'v' => Some(Direction::South),
-        '<' => Some(Direction::West),
-        '>' => Some(Direction::East),
-        _ => None,
-    });
-
-    (
-        Some(solve_part(grid_1, moves.clone())),
-        Some(solve_part(grid_2, moves.clone())),
-    )
+
+        Ok((grid, moves))
+    }
+
+    fn part1((grid, moves): &Self::Input<'_>) -> Option<Self::Output1> {
+        Some(solve_part(grid.clone(), moves.iter().copied()))
+    }
+
+    fn part2((grid, moves): &Self::Input<'_>) -> Option<Self::Output2> {
+        let wide_grid = {
+            let width = grid.width() * 2;
+            let data = grid
+                .iter()
+                .flat_map(|(_, c)| match c {
+                    '#' => ['#', '#'],
+                    'O' => ['[', ']'],
+                    '.' => ['.', '.'],
+                    '@' => ['@', '.'],
+                    _ => unreachable!(),
+                })
+                .collect();
+            VecGrid::from_data(width, data)
+        };
+
+        Some(solve_part(wide_grid, moves.iter().copied()))
+    }
 }
 
 fn solve_part(mut grid: VecGrid<char>, moves: impl Iterator<Item = Direction>) -> usize {
@@ -133,7 +169,8 @@ fn solve_part(mut grid: VecGrid<char>, moves: impl Iterator<Item = Direction>) -
 
 #[cfg(test)]
 mod tests {
-    use super::solve;
+    use super::Solution;
+    use crate::days::Day;
     use indoc::indoc;
 
     #[test]
@@ -161,6 +198,9 @@ mod tests {
             ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
             v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
         "};
-        assert_eq!(solve(example_input), (Some(10092), Some(9021)));
+        assert_eq!(
+            Solution::solve(example_input),
+            Ok((Some(10092), Some(9021)))
+        );
     }
 }
@@ -0,0 +1,325 @@
+use crate::days::Day;
+use crate::error::{Error, Result};
+use crate::params::{Param, Params};
+use crate::util::grid::{Grid as _, StrGrid};
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Index;
+
+pub const DAY: usize = 16;
+
+pub struct Grid<'a> {
+    data: &'a [u8],
+    width: usize,
+    start: Coord,
+    end: Coord,
+    move_cost: usize,
+    turn_cost: usize,
+}
+
+impl<'a> Grid<'a> {
+    fn new(input: &'a str, move_cost: usize, turn_cost: usize) -> Result<Self> {
+        let grid = StrGrid::new(input)?;
+
+        // The maze must be enclosed by walls, otherwise `step` could leave the grid.
+        let (width, height) = (grid.width(), grid.height());
+        for ((x, y), &c) in grid.iter() {
+            if (x == 0 || y == 0 || x == width - 1 || y == height - 1) && c != b'#' {
+                return Err(Error::at(
+                    y + 1,
+                    x + 1,
+                    "expected the maze to be enclosed by walls (#)",
+                ));
+            }
+        }
+
+        let find = |tile: u8, name: &str| {
+            grid.data()
+                .iter()
+                .position(|&c| c == tile)
+                .map(Coord)
+                .ok_or_else(|| Error::new(format!("no {} ({}) found", name, tile as char)))
+        };
+
+        Ok(Self {
+            data: grid.data(),
+            width: width + 1,
+            start: find(b'S', "start")?,
+            end: find(b'E', "end")?,
+            move_cost,
+            turn_cost,
+        })
+    }
+
+    /// The directions to continue in when facing `dir`, with the score of moving one step that way.
+    fn moves(&self, dir: Direction) -> [(Direction, usize); 3] {
+        let turn_and_move_cost = self.turn_cost + self.move_cost;
+        [
+            (dir, self.move_cost),
+            (dir.clockwise(), turn_and_move_cost),
+            (dir.counter_clockwise(), turn_and_move_cost),
+        ]
+    }
+
+    fn start_coord(&self) -> Coord {
+        self.start
+    }
+    fn end_coord(&self) -> Coord {
+        self.end
+    }
+
+    fn step(&self, coord: Coord, direction: Direction) -> Coord {
+        match direction {
+            Direction::NORTH => Coord(coord.0 - self.width),
+            Direction::EAST => Coord(coord.0 + 1),
+            Direction::SOUTH => Coord(coord.0 + self.width),
+            Direction::WEST => Coord(coord.0 - 1),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Index<Coord> for Grid<'_> {
+    type Output = u8;
+
+    fn index(&self, index: Coord) -> &Self::Output {
+        self.data.index(index.0)
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct Coord(usize);
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct Direction(usize);
+
+impl Direction {
+    const NORTH: Self = Self(0);
+    const EAST: Self = Self(1);
+    const SOUTH: Self = Self(2);
+    const WEST: Self = Self(3);
+
+    fn clockwise(self) -> Self {
+        Self((self.0 + 1) % 4)
+    }
+    fn counter_clockwise(self) -> Self {
+        Self((self.0 + 3) % 4)
+    }
+    fn opposite(self) -> Self {
+        Self((self.0 + 2) % 4)
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+struct State(usize);
+
+impl State {
+    fn new(coord: Coord, dir: Direction) -> Self {
+        Self((coord.0 << 2) + dir.0)
+    }
+
+    fn coord(self) -> Coord {
+        Coord(self.0 >> 2)
+    }
+    fn dir(self) -> Direction {
+        Direction(self.0 % 4)
+    }
+
+    fn with_score(self, score: usize) -> StateWithScore {
+        StateWithScore { state: self, score }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct StateWithScore {
+    state: State,
+    score: usize,
+}
+
+impl PartialOrd<Self> for StateWithScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StateWithScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score).reverse()
+    }
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input<'a> = Maze<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const PARAMS: &'static [Param] = &[Param::new("move-cost", 1), Param::new("turn-cost", 1000)];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>> {
+        let positive = |name: &str| match params.get::<isize>(name)? {
+            value if value > 0 => Ok(value as usize),
+            value => Err(Error::new(format!(
+                "parameter '{}' must be positive, got {}",
+                name, value
+            ))),
+        };
+
+        Ok(Maze {
+            grid: Grid::new(input, positive("move-cost")?, positive("turn-cost")?)?,
+            lowest_scores: OnceCell::new(),
+        })
+    }
+
+    fn part1(maze: &Self::Input<'_>) -> Option<Self::Output1> {
+        let (lowest_score, _) = maze.lowest_scores();
+        *lowest_score
+    }
+
+    fn part2(maze: &Self::Input<'_>) -> Option<Self::Output2> {
+        let grid = &maze.grid;
+        let (lowest_score, lowest_score_map) = maze.lowest_scores();
+        let lowest_score = (*lowest_score)?;
+        let end_coord = grid.end_coord();
+
+        // Backtrack from end coord (facing North or East), counting cells where backtrack_score +
+        // cache_score == lowest_score.
+        let mut best_path_coords = vec![false; grid.data.len()];
+        let mut num_best_path_coords = 0;
+        let mut visited = vec![false; lowest_score_map.len()];
+        let mut queue = VecDeque::new();
+
+        queue.push_back(State::new(end_coord, Direction::NORTH).with_score(lowest_score));
+        queue.push_back(State::new(end_coord, Direction::EAST).with_score(lowest_score));
+
+        while let Some(state) = queue.pop_front() {
+            if lowest_score_map[state.state.0] == usize::MAX
+                || lowest_score_map[state.state.0] != state.score
+                || visited[state.state.0]
+            {
+                continue;
+            }
+            visited[state.state.0] = true;
+
+            let prev_states = grid
+                .moves(state.state.dir())
+                .map(|(dir, score)| StateWithScore {
+                    state: State::new(
+                        grid.step(state.state.coord(), state.state.dir().opposite()),
+                        dir,
+                    ),
+                    score: state.score.wrapping_sub(score),
+                });
+
+            queue.extend(prev_states);
+
+            if !best_path_coords[state.state.coord().0] {
+                best_path_coords[state.state.coord().0] = true;
+                num_best_path_coords += 1;
+            }
+        }
+
+        Some(num_best_path_coords)
+    }
+}
+
+/// The maze along with the result of [`find_lowest_scores`], which both parts need.
+pub struct Maze<'a> {
+    grid: Grid<'a>,
+    lowest_scores: OnceCell<(Option<usize>, Vec<usize>)>,
+}
+
+impl Maze<'_> {
+    /// Runs the search on first use, so that it counts towards the part that runs first.
+    fn lowest_scores(&self) -> &(Option<usize>, Vec<usize>) {
+        self.lowest_scores
+            .get_or_init(|| find_lowest_scores(&self.grid))
+    }
+}
+
+/// Finds the path with the lowest score. Once it is found, keeps searching until all paths with
+/// that score are discovered. Returns the lowest score and the lowest score of each visited state.
+fn find_lowest_scores(grid: &Grid) -> (Option<usize>, Vec<usize>) {
+    let start_coord = grid.start_coord();
+    let end_coord = grid.end_coord();
+
+    let mut lowest_score_map = vec![usize::MAX; grid.data.len() * 4];
+    let mut lowest_score = None;
+
+    let mut queue = BinaryHeap::new();
+
+    let start_state = State::new(start_coord, Direction::EAST).with_score(0);
+
+    queue.push(start_state);
+    lowest_score_map[start_state.state.0] = start_state.score;
+
+    while let Some(StateWithScore { state, score }) = queue.pop() {
+        if let Some(lowest_score) = lowest_score {
+            if score > lowest_score {
+                break;
+            }
+        }
+
+        let next_states = grid.moves(state.dir()).map(|(dir, delta_score)| {
+            State::new(grid.step(state.coord(), dir), dir).with_score(score + delta_score)
+        });
+
+        for next_state in next_states {
+            if grid[next_state.state.coord()] == b'#' {
+                // Don't crash into walls.
+                continue;
+            }
+            if lowest_score_map[next_state.state.0] != usize::MAX {
+                // Already found a lower-score path to this state.
+                continue;
+            }
+            if next_state.state.coord() == end_coord {
+                lowest_score = Some(next_state.score);
+            }
+            queue.push(next_state);
+            lowest_score_map[next_state.state.0] = next_state.score;
+        }
+    }
+
+    (lowest_score, lowest_score_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::days::Day;
+    use crate::params::Params;
+    use indoc::indoc;
+
+    #[test]
+    fn test_solve() {
+        let example_input = indoc! {"
+            ###############
+            #.......#....E#
+            #.#.###.#.###.#
+            #.....#.#...#.#
+            #.###.#####.#.#
+            #.#.#.......#.#
+            #.#.#####.###.#
+            #...........#.#
+            ###.#.#####.#.#
+            #...#.....#.#.#
+            #.#.#.###.#.#.#
+            #.....#...#.#.#
+            #.###.#.#.#.#.#
+            #S..#.....#...#
+            ###############
+        "};
+        assert_eq!(Solution::solve(example_input), Ok((Some(7036), Some(45))));
+
+        let free_turns = "turn-cost=0".parse().unwrap();
+        let params = Params::new(Solution::PARAMS, &[free_turns]).unwrap();
+        assert!(Solution::parse_with(example_input, &params).is_err());
+    }
+}
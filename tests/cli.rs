@@ -0,0 +1,493 @@
+//! End-to-end tests of the network commands against the mock server, and of the `serve` command.
+
+mod common;
+
+use common::mock::MockServer;
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+const SESSION: &str = "test-session";
+const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// A working directory for the CLI and a mock server serving `mock/` inside it.
+struct Setup {
+    dir: TempDir,
+    server: MockServer,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start(dir.path().join("mock"), SESSION, 0).unwrap();
+        Self { dir, server }
+    }
+
+    /// Writes `contents` to `mock/2024/dayNN/name`.
+    fn serve(&self, day: usize, name: &str, contents: &str) {
+        let path = self.path(&format!("mock/2024/day{:02}/{}", day, name));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.dir.path().join(path)
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        self.run_with_session(args, Some(SESSION))
+    }
+
+    /// Runs the CLI with `AOC_SESSION` set to `session`, or unset if `None`.
+    fn run_with_session(&self, args: &[&str], session: Option<&str>) -> Output {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2024"));
+        command
+            .args(args)
+            .current_dir(self.dir.path())
+            .env("AOC_BASE_URL", self.server.url())
+            .env("HOME", self.dir.path());
+        match session {
+            Some(session) => command.env("AOC_SESSION", session),
+            None => command.env_remove("AOC_SESSION"),
+        };
+        command.output().unwrap()
+    }
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn read(path: impl AsRef<Path>) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+/// Builds the example plugin into the target directory and profile of the binary under test, and
+/// returns the path of the library.
+fn build_plugin() -> PathBuf {
+    let profile_dir = Path::new(env!("CARGO_BIN_EXE_advent-of-code-2024"))
+        .parent()
+        .unwrap();
+    let profile = match profile_dir.file_name().unwrap().to_str().unwrap() {
+        "debug" => "dev",
+        profile => profile,
+    };
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "build",
+            "--quiet",
+            "--example",
+            "plugin",
+            "--profile",
+            profile,
+        ])
+        .arg("--target-dir")
+        .arg(profile_dir.parent().unwrap())
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    profile_dir
+        .join("examples")
+        .join(format!("{}plugin{}", DLL_PREFIX, DLL_SUFFIX))
+}
+
+#[test]
+fn test_download() {
+    let setup = Setup::new();
+    setup.serve(1, "input.txt", EXAMPLE);
+
+    let output = setup.run(&["download", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(read(setup.path("inputs/2024/day01.txt")), EXAMPLE);
+
+    let requests = setup.server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2024/day/1/input");
+    assert!(requests[0]
+        .user_agent
+        .as_deref()
+        .is_some_and(|user_agent| user_agent.starts_with("github.com/Mesoptier")));
+
+    // Existing inputs are only replaced with --force.
+    fs::write(setup.path("inputs/2024/day01.txt"), "edited\n").unwrap();
+    let output = setup.run(&["download", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("already exists"));
+    assert_eq!(read(setup.path("inputs/2024/day01.txt")), "edited\n");
+
+    let output = setup.run(&["download", "1", "--force"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(read(setup.path("inputs/2024/day01.txt")), EXAMPLE);
+}
+
+#[test]
+fn test_download_errors() {
+    let setup = Setup::new();
+    setup.serve(1, "input.txt", EXAMPLE);
+    setup.serve(2, "reply.txt", "locked");
+    setup.serve(3, "reply.txt", "server-error");
+
+    for (args, session, error) in [
+        (["download", "1"], "wrong-session", "bad request (400)"),
+        (["download", "2"], SESSION, "puzzle not unlocked yet"),
+        (["download", "3"], SESSION, "server error (500"),
+        (["download", "4"], SESSION, "not found (404)"),
+    ] {
+        let output = setup.run_with_session(&args, Some(session));
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains(error), "{}", stderr(&output));
+    }
+    assert!(!setup.path("inputs/2024/day01.txt").exists());
+}
+
+#[test]
+fn test_download_all() {
+    let setup = Setup::new();
+    setup.serve(1, "input.txt", EXAMPLE);
+    setup.serve(2, "input.txt", "1 2 3\n");
+    fs::create_dir_all(setup.path("inputs/2024")).unwrap();
+    fs::write(setup.path("inputs/2024/day01.txt"), "cached\n").unwrap();
+
+    let output = setup.run(&["download", "--all", "--delay", "0"]);
+    // The mock server does not have days 3 to 25.
+    assert!(!output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("Fetched: 1 (2)"), "{}", stdout);
+    assert!(stdout.contains("Cached: 1 (1)"), "{}", stdout);
+    assert!(stdout.contains("Failed: 23 "), "{}", stdout);
+    assert_eq!(read(setup.path("inputs/2024/day01.txt")), "cached\n");
+    assert_eq!(read(setup.path("inputs/2024/day02.txt")), "1 2 3\n");
+    assert!(setup
+        .server
+        .requests()
+        .iter()
+        .all(|request| request.path != "/2024/day/1/input"));
+}
+
+#[test]
+fn test_new_invalid_day() {
+    let setup = Setup::new();
+    for day in ["0", "26"] {
+        let output = setup.run(&["new", day]);
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("is not between 1 and 25"));
+    }
+    assert!(!setup.path("src").exists());
+}
+
+#[test]
+fn test_describe() {
+    let setup = Setup::new();
+    setup.serve(1, "puzzle.html", include_str!("fixtures/day01.html"));
+
+    let output = setup.run(&["describe", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(read(setup.path("puzzles/2024/day01.md"))
+        .starts_with("## --- Day 1: Historian Hysteria ---\n"));
+    assert_eq!(read(setup.path("inputs/2024/day01.example-1.txt")), EXAMPLE);
+    assert_eq!(
+        read(setup.path("inputs/2024/day01.example-2.txt")),
+        "a > b\n"
+    );
+
+    let output = setup.run(&["describe", "1", "--puzzles-dir", "docs"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(setup.path("docs/2024/day01.md").exists());
+}
+
+#[test]
+fn test_submit() {
+    let setup = Setup::new();
+    setup.serve(1, "answer-1.txt", "10\n");
+    setup.serve(1, "answer-2.txt", "31\n");
+    fs::create_dir_all(setup.path("inputs/2024")).unwrap();
+    fs::write(setup.path("inputs/2024/day01.txt"), EXAMPLE).unwrap();
+
+    let output = setup.run(&["submit", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("11 is too high"),
+        "{}",
+        stdout(&output)
+    );
+
+    // The same answer is refused without asking the server again.
+    let output = setup.run(&["submit", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("already guessed"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(setup.server.requests().len(), 1);
+
+    let output = setup.run(&["submit", "1", "2"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("31 is correct"));
+    assert_eq!(
+        read(setup.path("answers/2024/day01.guesses.txt")),
+        "part 1 too high: 11\npart 2 correct: 31\n"
+    );
+
+    setup.serve(1, "reply.txt", "rate-limited");
+    fs::remove_file(setup.path("answers/2024/day01.guesses.txt")).unwrap();
+    let output = setup.run(&["submit", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("rate limited, wait 30s"),
+        "{}",
+        stdout(&output)
+    );
+}
+
+#[test]
+fn test_leaderboard() {
+    let setup = Setup::new();
+    let json = include_str!("fixtures/leaderboard.json");
+    fs::create_dir_all(setup.path("mock/2024/leaderboard")).unwrap();
+    fs::write(setup.path("mock/2024/leaderboard/1.json"), json).unwrap();
+
+    let output = setup.run(&["leaderboard", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("   1 | Alice               |     9 |     3\n"));
+
+    // A saved leaderboard is shown without asking the server.
+    fs::write(setup.path("saved.json"), json).unwrap();
+    let saved = setup.run(&["leaderboard", "1", "--json", "saved.json"]);
+    assert_eq!(stdout(&saved), stdout(&output));
+    assert_eq!(setup.server.requests().len(), 1);
+}
+
+#[test]
+fn test_session() {
+    let setup = Setup::new();
+    fs::create_dir_all(setup.path("mock")).unwrap();
+    fs::write(setup.path("mock/user.txt"), "Alice\n").unwrap();
+
+    let output = setup.run_with_session(&["session", "whoami"], None);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no session"),
+        "{}",
+        stderr(&output)
+    );
+
+    let output = setup.run_with_session(&["session", "set", SESSION], None);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(!stdout(&output).contains(SESSION));
+    let session_path = setup.path(".adventofcode.session");
+    assert_eq!(read(&session_path), format!("{}\n", SESSION));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&session_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let output = setup.run_with_session(&["session", "whoami"], None);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).starts_with("Logged in as Alice (session test… (redacted)"));
+
+    let output = setup.run_with_session(&["session", "whoami"], Some("other-session"));
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("is not logged in"));
+    assert!(!stderr(&output).contains("other-session"));
+
+    let output = setup.run_with_session(&["session", "clear"], None);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(!session_path.exists());
+}
+
+#[test]
+fn test_config() {
+    let setup = Setup::new();
+    setup.serve(1, "input.txt", EXAMPLE);
+    fs::write(
+        setup.path("aoc.toml"),
+        "inputs-dir = \"data\"\nformat = \"csv\"\n",
+    )
+    .unwrap();
+
+    let output = setup.run(&["download", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(read(setup.path("data/2024/day01.txt")), EXAMPLE);
+
+    // The format from `aoc.toml` applies, unless overridden on the command line.
+    let output = setup.run(&["solve", "1"]);
+    assert!(
+        stdout(&output).starts_with("year,day,status,"),
+        "{}",
+        stdout(&output)
+    );
+    let output = setup.run(&["solve", "1", "--format", "text"]);
+    assert!(
+        stdout(&output).contains("Part 1: 11"),
+        "{}",
+        stdout(&output)
+    );
+
+    // Environment variables override `aoc.toml`, and flags override both.
+    let mut command = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2024"));
+    let output = command
+        .args(["download", "1", "--inputs-dir", "flag"])
+        .current_dir(setup.dir.path())
+        .env("AOC_BASE_URL", setup.server.url())
+        .env("AOC_SESSION", SESSION)
+        .env("AOC_INPUTS_DIR", "env")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(setup.path("flag/2024/day01.txt").exists());
+    assert!(!setup.path("env").exists());
+
+    fs::write(setup.path("aoc.toml"), "yaer = 2023\n").unwrap();
+    let output = setup.run(&["solve", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("aoc.toml: unknown field `yaer`"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_serve() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2024"))
+        .args(["serve", "--port", "0"])
+        .current_dir(dir.path())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    let url = line.trim().rsplit(' ').next().unwrap().to_string();
+
+    let client = reqwest::blocking::Client::new();
+    let days = client.get(format!("{}/days", url)).send().unwrap();
+    assert_eq!(days.status(), 200);
+    assert!(days.text().unwrap().contains("\"days\":[1,"));
+
+    let solved = client
+        .post(format!("{}/solve/1", url))
+        .body(EXAMPLE)
+        .send()
+        .unwrap();
+    assert_eq!(solved.status(), 200);
+    let json: serde_json::Value = serde_json::from_str(&solved.text().unwrap()).unwrap();
+    assert_eq!(json["part_1"]["value"], "11");
+    assert_eq!(json["part_2"]["value"], "31");
+    assert!(json["elapsed_ns"].is_u64());
+
+    let failed = client
+        .post(format!("{}/solve/1", url))
+        .body("x\n")
+        .send()
+        .unwrap();
+    assert_eq!(failed.status(), 422);
+    let json: serde_json::Value = serde_json::from_str(&failed.text().unwrap()).unwrap();
+    assert!(
+        json["error"].as_str().unwrap().starts_with("line 1"),
+        "{}",
+        json
+    );
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn test_plugin() {
+    let plugin = build_plugin();
+
+    let setup = Setup::new();
+    fs::create_dir_all(setup.path("plugins")).unwrap();
+    fs::copy(
+        &plugin,
+        setup.path(&format!("plugins/counting{}", DLL_SUFFIX)),
+    )
+    .unwrap();
+    fs::write(setup.path("input.txt"), EXAMPLE).unwrap();
+
+    let output = setup.run(&[
+        "solve",
+        "1",
+        "--variant",
+        "counting",
+        "--input",
+        "input.txt",
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("Part 1: 11"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stdout(&output).contains("Part 2: 31"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = setup.run(&["solve", "1", "--variant", "other", "--input", "input.txt"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no solver has variant 'other'"));
+}
+
+#[test]
+fn test_params() {
+    let setup = Setup::new();
+    fs::write(
+        setup.path("example.txt"),
+        "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\np=3,0 v=-2,-2\n\
+         p=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3\n",
+    )
+    .unwrap();
+
+    let solve = |params: &[&str]| {
+        let mut args = vec!["solve", "14", "--part", "1", "--input", "example.txt"];
+        for param in params {
+            args.extend(["--param", param]);
+        }
+        setup.run(&args)
+    };
+
+    let output = solve(&["width=11", "height=7"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("Part 1: 12"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = solve(&["depth=3"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("unknown parameter 'depth'"),
+        "{}",
+        stderr(&output)
+    );
+
+    let output = solve(&["width=0"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("parameter 'width' must be positive"));
+
+    let output = solve(&["width"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("expected 'name=value'"));
+
+    let output = setup.run(&["solve", "1..=14", "--param", "width=11"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--param can only be used with a single day"));
+}
@@ -0,0 +1,267 @@
+//! A local stand-in for the Advent of Code server, used to test the network commands offline.
+//!
+//! It serves files from a directory laid out as:
+//!
+//! ```text
+//! <root>/<year>/dayNN/input.txt     the puzzle input
+//! <root>/<year>/dayNN/puzzle.html   the puzzle page
+//! <root>/<year>/dayNN/answer-P.txt  the correct answer of part P, for judging submissions
+//! <root>/<year>/dayNN/reply.txt     optionally one of `not-found`, `server-error`, `locked` or
+//!                                   `rate-limited`, to simulate that reply for the day
+//! <root>/<year>/leaderboard/ID.json a private leaderboard
+//! <root>/user.txt                   the account name shown to logged in users
+//! ```
+//!
+//! Inputs, submissions and leaderboards require the session cookie the server was started with.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const NOT_UNLOCKED: &str = "Please don't repeatedly request this endpoint before it unlocks! The \
+    calendar countdown is synchronized with the server time; the link will be enabled on the \
+    calendar the instant this puzzle becomes available.";
+const NOT_LOGGED_IN: &str =
+    "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+
+/// A request the server received.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Starts serving `root` on `127.0.0.1:port`, or on a free port if `port` is 0.
+    pub fn start(root: impl Into<PathBuf>, session: &str, port: u16) -> io::Result<Self> {
+        let server = Server::http(("127.0.0.1", port)).map_err(io::Error::other)?;
+        let server = Arc::new(server);
+        let url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let thread = {
+            let server = server.clone();
+            let requests = requests.clone();
+            let root = root.into();
+            let session = session.to_string();
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(&root, &session, &requests, request);
+                }
+            })
+        };
+
+        Ok(Self {
+            server,
+            url,
+            requests,
+            thread: Some(thread),
+        })
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:4321`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Serves requests until the process is stopped.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            thread.join().unwrap();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn handle(
+    root: &Path,
+    session: &str,
+    requests: &Mutex<Vec<RecordedRequest>>,
+    mut request: Request,
+) {
+    let header = |name: &'static str| {
+        request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv(name))
+            .map(|header| header.value.to_string())
+    };
+    let user_agent = header("User-Agent");
+    let logged_in = header("Cookie").is_some_and(|cookie| {
+        cookie
+            .split(';')
+            .any(|cookie| cookie.trim() == format!("session={}", session))
+    });
+
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+    let method = request.method().clone();
+    let path = request.url().to_string();
+    requests.lock().unwrap().push(RecordedRequest {
+        method: method.to_string(),
+        path: path.clone(),
+        user_agent,
+        body: body.clone(),
+    });
+
+    let (status, body) = reply(root, &method, &path, logged_in, &body);
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap());
+    let _ = request.respond(response);
+}
+
+/// Wraps a message like the server does on its reply pages.
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+fn reply(root: &Path, method: &Method, path: &str, logged_in: bool, body: &str) -> (u16, String) {
+    let not_found = (404, "404 Not Found\n".to_string());
+
+    // Paths look like `/2024/day/1`, `/2024/day/1/input` and `/2024/day/1/answer`.
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    if let [year, "leaderboard", "private", "view", file] = segments[..] {
+        let leaderboard = root.join(year).join("leaderboard").join(file);
+        return match fs::read_to_string(leaderboard) {
+            // The server redirects to the login page instead.
+            Ok(_) if !logged_in => (302, String::new()),
+            Ok(json) => (200, json),
+            Err(_) => not_found,
+        };
+    }
+    if let [year] = segments[..] {
+        if year.parse::<usize>().is_err() {
+            return not_found;
+        }
+        let user = match logged_in {
+            true => {
+                let name = fs::read_to_string(root.join("user.txt"));
+                let name = name.as_deref().map_or("(anonymous user #1)", str::trim);
+                format!(
+                    "<div class=\"user\">{} <span class=\"star-count\">0*</span></div>",
+                    name
+                )
+            }
+            false => format!("<a href=\"/{}/auth/login\">[Log In]</a>", year),
+        };
+        return (
+            200,
+            format!("<html><body><header>{}</header></body></html>\n", user),
+        );
+    }
+    let (year, day, endpoint) = match segments[..] {
+        [year, "day", day] => (year, day, None),
+        [year, "day", day, endpoint] => (year, day, Some(endpoint)),
+        _ => return not_found,
+    };
+    let (Ok(year), Ok(day)) = (year.parse::<usize>(), day.parse::<usize>()) else {
+        return not_found;
+    };
+    let day_dir = root.join(year.to_string()).join(format!("day{:02}", day));
+    let read = |name: &str| fs::read_to_string(day_dir.join(name)).ok();
+
+    let simulated = read("reply.txt").map(|reply| reply.trim().to_string());
+    match simulated.as_deref() {
+        Some("not-found") => return not_found,
+        Some("server-error") => return (500, "Internal Server Error\n".to_string()),
+        Some("locked") => return (404, NOT_UNLOCKED.to_string()),
+        _ => {}
+    }
+
+    match (method, endpoint) {
+        (Method::Get, None) => read("puzzle.html").map_or(not_found, |html| (200, html)),
+        (Method::Get, Some("input")) if !logged_in => (400, NOT_LOGGED_IN.to_string()),
+        (Method::Get, Some("input")) => read("input.txt").map_or(not_found, |input| (200, input)),
+        (Method::Post, Some("answer")) if !logged_in => (400, NOT_LOGGED_IN.to_string()),
+        (Method::Post, Some("answer")) if simulated.as_deref() == Some("rate-limited") => (
+            200,
+            page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 30s left to wait.",
+            ),
+        ),
+        (Method::Post, Some("answer")) => {
+            let level = form_value(body, "level").unwrap_or_default();
+            let answer = form_value(body, "answer").unwrap_or_default();
+            let message = match read(&format!("answer-{}.txt", level)) {
+                None => "You don't seem to be solving the right level.  Did you already complete \
+                         it?"
+                .to_string(),
+                Some(expected) => judge(expected.trim(), answer.trim()),
+            };
+            (200, page(&message))
+        }
+        _ => not_found,
+    }
+}
+
+fn judge(expected: &str, answer: &str) -> String {
+    if answer == expected {
+        return "That's the right answer!  You are one gold star closer to finding the Chief \
+                Historian."
+            .to_string();
+    }
+
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "  Your answer is too high.",
+        (Ok(answer), Ok(expected)) if answer < expected => "  Your answer is too low.",
+        _ => "",
+    };
+    format!(
+        "That's not the right answer.{}  Please wait one minute before trying again.",
+        hint
+    )
+}
+
+/// Finds `name` in a URL-encoded form body.
+fn form_value(body: &str, name: &str) -> Option<String> {
+    let value = body
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))?;
+
+    let mut bytes = vec![];
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' if tail.len() >= 2 => {
+                let hex = std::str::from_utf8(&tail[..2]).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                rest = &tail[2..];
+                continue;
+            }
+            byte => bytes.push(byte),
+        }
+        rest = tail;
+    }
+    String::from_utf8(bytes).ok()
+}
@@ -0,0 +1,3 @@
+// Also used by `examples/mock-server.rs`; each uses only part of the server.
+#[allow(dead_code)]
+pub mod mock;